@@ -0,0 +1,73 @@
+//! Persisted global-hotkey binding used to summon and dismiss the wallet
+//! approval window. Nothing is registered until the app has loaded a
+//! binding (the default the first time), and whatever the user reconfigures
+//! it to sticks across launches, the same way approved origins do.
+
+use std::{fs, path::PathBuf, sync::Mutex};
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+const HOTKEY_FILE: &str = "hotkey.json";
+/// Summon/dismiss chord used the first time the app ever launches.
+pub const DEFAULT_HOTKEY: &str = "CommandOrControl+Shift+U";
+
+#[derive(Serialize, Deserialize)]
+struct HotkeyData {
+    shortcut: String,
+}
+
+impl Default for HotkeyData {
+    fn default() -> Self {
+        Self {
+            shortcut: DEFAULT_HOTKEY.to_string(),
+        }
+    }
+}
+
+pub struct HotkeyStore {
+    path: PathBuf,
+    data: Mutex<HotkeyData>,
+}
+
+impl HotkeyStore {
+    pub fn load(app: &AppHandle) -> Result<Self, String> {
+        let mut dir = app.path().app_local_data_dir().map_err(|e| e.to_string())?;
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+        dir.push(HOTKEY_FILE);
+
+        let data = fs::read_to_string(&dir)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+
+        Ok(Self {
+            path: dir,
+            data: Mutex::new(data),
+        })
+    }
+
+    pub fn shortcut(&self) -> String {
+        self.data
+            .lock()
+            .expect("hotkey store mutex poisoned")
+            .shortcut
+            .clone()
+    }
+
+    /// Replace the configured shortcut and persist immediately.
+    pub fn set_shortcut(&self, shortcut: String) -> Result<(), String> {
+        {
+            let mut data = self.data.lock().expect("hotkey store mutex poisoned");
+            data.shortcut = shortcut;
+        }
+        self.persist()
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let data = self.data.lock().expect("hotkey store mutex poisoned");
+        let json = serde_json::to_string_pretty(&*data)
+            .map_err(|e| format!("failed to serialize hotkey binding: {e}"))?;
+        fs::write(&self.path, json).map_err(|e| format!("failed to persist hotkey binding: {e}"))
+    }
+}
@@ -8,13 +8,14 @@
 use std::ffi::{c_void, CStr};
 use std::{
     convert::Infallible,
-    fs,
+    env, fs,
     net::SocketAddr,
     path::{Path, PathBuf},
     sync::{
         atomic::{AtomicU64, Ordering},
         Arc,
     },
+    time::{Duration, Instant},
 };
 
 #[cfg(target_os = "macos")]
@@ -38,6 +39,7 @@ extern "C" {}
 extern "C" {}
 
 // Third-party imports.
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 use dashmap::DashMap;
 use hyper::header::HeaderValue;
 use hyper::{
@@ -45,10 +47,15 @@ use hyper::{
     service::{make_service_fn, service_fn},
     Body, Request, Response, Server, StatusCode,
 };
+use rand::Rng;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use socket2::{SockRef, TcpKeepalive};
 use tauri::{Emitter, Listener, WebviewUrl, WebviewWindow, WebviewWindowBuilder, Window};
-use tokio::{net::TcpListener, sync::oneshot};
+use tokio::{
+    net::TcpListener,
+    sync::{oneshot, Semaphore},
+};
 use tokio_rustls::TlsAcceptor;
 use url::Url;
 
@@ -59,17 +66,30 @@ use tauri::menu::{MenuBuilder, MenuItemBuilder};
 #[cfg(any(target_os = "macos", target_os = "windows", target_os = "linux"))]
 use tauri::tray::TrayIconBuilder;
 use tauri::WindowEvent;
-use tauri::{command, AppHandle, Manager};
+use tauri::{command, AppHandle, Manager, State};
 
 #[cfg(any(target_os = "macos", target_os = "windows", target_os = "linux"))]
 struct TrayHolder {
     _icon: tauri::tray::TrayIcon,
 }
 
+mod hotkey;
+mod metrics;
+mod origins;
 mod priority;
+#[cfg(feature = "quic")]
+mod quic;
+mod server_tuning;
+mod shutdown;
 mod tls;
+use hotkey::HotkeyStore;
+use metrics::Metrics;
+use origins::OriginStore;
 use priority::{elevate_current_thread_priority, elevate_process_priority};
-use tls::ensure_localhost_tls;
+use server_tuning::ServerTuning;
+use shutdown::{Shutdown, SHUTDOWN_DRAIN_TIMEOUT};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+use tls::{ensure_localhost_tls, export_pkcs12, reload_certificate, sync_certificate_from_disk, LocalhostTls};
 
 // (no direct plugin imports; we call plugin initializers via fully-qualified paths)
 
@@ -90,6 +110,63 @@ async fn save_file(path: String, contents: Vec<u8>) -> Result<(), String> {
     Ok(())
 }
 
+#[derive(Serialize)]
+struct ExportPkcs12Response {
+    path: String,
+    passphrase: String,
+}
+
+/// Export the localhost identity as a password-protected PKCS#12 bundle for
+/// manual/enterprise import into tools that can't consume our PEM files.
+#[tauri::command]
+fn export_localhost_cert_p12(
+    app_handle: AppHandle,
+    passphrase: Option<String>,
+) -> Result<ExportPkcs12Response, String> {
+    let (path, passphrase) = export_pkcs12(&app_handle, passphrase)?;
+    Ok(ExportPkcs12Response {
+        path: path.to_string_lossy().into_owned(),
+        passphrase,
+    })
+}
+
+/// Regenerates the localhost TLS leaf if it's due for renewal and swaps it
+/// into the live server config, so callers never need to restart the app to
+/// pick up a fresh cert. Returns true if a new cert was actually installed.
+#[tauri::command]
+fn reload_tls(app_handle: AppHandle, tls_state: State<Arc<LocalhostTls>>) -> Result<bool, String> {
+    reload_certificate(&app_handle, &tls_state)
+}
+
+/// Unregister whatever summon/dismiss hotkey is currently bound and register
+/// `shortcut` in its place. Only one chord is ever active, so clearing
+/// everything first keeps this idempotent regardless of what was bound
+/// before.
+fn register_hotkey(app: &AppHandle, shortcut: &str) -> Result<(), String> {
+    let parsed: Shortcut = shortcut
+        .parse()
+        .map_err(|e| format!("invalid hotkey '{shortcut}': {e}"))?;
+    let manager = app.global_shortcut();
+    manager
+        .unregister_all()
+        .map_err(|e| format!("failed to clear previous hotkey: {e}"))?;
+    manager
+        .register(parsed)
+        .map_err(|e| format!("failed to register hotkey '{shortcut}': {e}"))
+}
+
+/// Reconfigure the global summon/dismiss hotkey at runtime and persist the
+/// new binding so it survives the next launch.
+#[tauri::command]
+fn set_hotkey(
+    app_handle: AppHandle,
+    hotkey_store: State<Arc<HotkeyStore>>,
+    shortcut: String,
+) -> Result<(), String> {
+    register_hotkey(&app_handle, &shortcut)?;
+    hotkey_store.set_shortcut(shortcut)
+}
+
 #[derive(Serialize)]
 struct ProxyFetchResponse {
     status: u16,
@@ -97,6 +174,10 @@ struct ProxyFetchResponse {
     body: String,
 }
 
+/// Optional upstream proxy (e.g. `socks5h://127.0.0.1:9050` for Tor) that
+/// `proxy_fetch_manifest` routes through when set. Unset means fetch direct.
+const MANIFEST_PROXY_ENV: &str = "USER_WALLET_MANIFEST_PROXY";
+
 #[tauri::command]
 async fn proxy_fetch_manifest(url: String) -> Result<ProxyFetchResponse, String> {
     let parsed = Url::parse(&url).map_err(|e| format!("invalid url: {e}"))?;
@@ -109,18 +190,29 @@ async fn proxy_fetch_manifest(url: String) -> Result<ProxyFetchResponse, String>
     }
 
     // Perform request
-    let client = Client::builder()
+    let mut client_builder = Client::builder()
         .user_agent("metanet-desktop/1.0 (+https://github.com/bsv-blockchain/metanet-desktop)")
-        .redirect(reqwest::redirect::Policy::limited(5))
-        .build()
-        .map_err(|e| e.to_string())?;
+        .redirect(reqwest::redirect::Policy::limited(5));
+
+    // Users behind Tor or a privacy proxy can route manifest fetches through
+    // it (e.g. `socks5h://127.0.0.1:9050`, which resolves DNS at the proxy
+    // too) by setting this. Left unset, we fetch direct as before.
+    if let Ok(proxy_uri) = env::var(MANIFEST_PROXY_ENV) {
+        let proxy = reqwest::Proxy::all(&proxy_uri)
+            .map_err(|e| format!("invalid manifest proxy uri '{proxy_uri}': {e}"))?;
+        client_builder = client_builder.proxy(proxy);
+    }
+
+    let client = client_builder.build().map_err(|e| e.to_string())?;
 
     let resp = client
         .get(parsed)
         .header(reqwest::header::ACCEPT, "application/json, */*;q=0.8")
         .send()
         .await
-        .map_err(|e| e.to_string())?;
+        // Fail closed: if the configured proxy is unreachable, surface that
+        // instead of letting the request quietly go out direct.
+        .map_err(|e| format!("manifest fetch failed: {e}"))?;
 
     let status = resp.status().as_u16();
     let mut headers_vec: Vec<(String, String)> = Vec::new();
@@ -146,7 +238,22 @@ struct HttpRequestEvent {
     path: String,
     headers: Vec<(String, String)>,
     body: String,
+    /// True when `body` is base64 (the raw bytes weren't valid UTF-8), so the
+    /// frontend knows whether to decode it before use.
+    body_is_base64: bool,
     request_id: u64,
+    origin: String,
+}
+
+/// How the frontend resolved a bridge request, so we can tell a deliberate
+/// user denial apart from a genuine internal error.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+enum BridgeOutcome {
+    #[default]
+    Success,
+    Denied,
+    Error,
 }
 
 /// Expected payload sent back from the frontend.
@@ -155,23 +262,80 @@ struct TsResponse {
     request_id: u64,
     status: u16,
     body: String,
+    /// True when `body` is base64-encoded raw bytes rather than plain text.
+    #[serde(default)]
+    body_is_base64: bool,
+    /// Extra headers (e.g. `Content-Type`) the frontend wants set on the
+    /// outgoing response, applied as given; when they carry no
+    /// `Content-Length`, hyper derives it from the body.
+    #[serde(default)]
+    headers: Vec<(String, String)>,
+    #[serde(default)]
+    outcome: BridgeOutcome,
+}
+
+/// Structured body returned for denials and errors instead of a bare status
+/// code, so callers can render something more useful than "403".
+#[derive(Serialize)]
+struct BridgeErrorBody<'a> {
+    error: &'a str,
+    message: &'a str,
+}
+
+/// A pending response slot, tracking when it was created so the reaper can
+/// evict it if the frontend never answers.
+struct PendingEntry {
+    sender: oneshot::Sender<TsResponse>,
+    inserted_at: Instant,
 }
 
 /// A type alias for our concurrent map of pending responses.
-type PendingMap = DashMap<u64, oneshot::Sender<TsResponse>>;
+pub(crate) type PendingMap = DashMap<u64, PendingEntry>;
+
+const BRIDGE_REQUEST_TIMEOUT_ENV: &str = "USER_WALLET_BRIDGE_REQUEST_TIMEOUT_SECS";
+
+/// How long a bridge request will wait for the frontend to respond before
+/// giving up with a 504. Overridable via `USER_WALLET_BRIDGE_REQUEST_TIMEOUT_SECS`
+/// for frontends that need longer than 120s to resolve a signing prompt.
+static BRIDGE_REQUEST_TIMEOUT: LazyLock<Duration> = LazyLock::new(|| {
+    env::var(BRIDGE_REQUEST_TIMEOUT_ENV)
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .map(Duration::from_secs)
+        .unwrap_or(Duration::from_secs(120))
+});
+/// How often the reaper sweeps `PendingMap` for entries past their deadline.
+const BRIDGE_REAPER_INTERVAL: Duration = Duration::from_secs(30);
+/// How often the background task checks whether the localhost TLS leaf is
+/// due for renewal.
+const TLS_RELOAD_CHECK_INTERVAL: Duration = Duration::from_secs(3600);
+/// How often the background task checks the cert/key files' mtimes for an
+/// out-of-band replacement, independent of our own renewal schedule.
+const CERT_WATCH_POLL_INTERVAL: Duration = Duration::from_secs(10);
+/// Path the bridge serves Prometheus-format metrics from; gated behind the
+/// same origin/token auth as every other bridge request.
+const BRIDGE_METRICS_PATH: &str = "/__bridge/metrics";
+
+/// Minimum gap between global-hotkey toggles. The two chord keys rarely land
+/// in the same millisecond and some OSes repeat a held combo; debouncing
+/// keeps a single physical press from summoning then immediately dismissing
+/// the window.
+const HOTKEY_DEBOUNCE: Duration = Duration::from_millis(350);
 
-#[cfg(target_os = "macos")]
 use std::sync::LazyLock;
 /// -----
 /// Tauri COMMANDS for focus management
 /// -----
 
-#[cfg(target_os = "macos")]
 use std::sync::Mutex;
 
 #[cfg(target_os = "macos")]
 static PREV_BUNDLE_ID: LazyLock<Mutex<Option<String>>> = LazyLock::new(|| Mutex::new(None));
 
+/// Last time the global hotkey fired a summon/dismiss toggle, for debouncing.
+static LAST_HOTKEY_TOGGLE: LazyLock<Mutex<Option<Instant>>> = LazyLock::new(|| Mutex::new(None));
+
 #[cfg(target_os = "macos")]
 #[allow(unexpected_cfgs)]
 fn capture_frontmost_bundle_identifier() -> Option<String> {
@@ -252,12 +416,19 @@ fn activate_application_by_bundle_id(bundle_id: &str) -> Result<(), String> {
     })
 }
 
-fn apply_cors_headers(res: &mut Response<Body>) {
+/// Reflects `origin` back as the only allowed origin instead of `*`, so the
+/// bridge never grants wallet access to a page we haven't approved.
+fn apply_cors_headers(res: &mut Response<Body>, origin: &str) {
     let headers = res.headers_mut();
-    headers.insert("Access-Control-Allow-Origin", HeaderValue::from_static("*"));
+    if let Ok(value) = HeaderValue::from_str(origin) {
+        headers.insert("Access-Control-Allow-Origin", value);
+    }
+    // The wildcard `*` doesn't cover `Authorization` per the Fetch spec, and
+    // our clients send either that or `X-Bridge-Token`, so list the headers
+    // the bridge actually expects instead of relying on the wildcard.
     headers.insert(
         "Access-Control-Allow-Headers",
-        HeaderValue::from_static("*"),
+        HeaderValue::from_static("Content-Type, X-Bridge-Token, Authorization"),
     );
     headers.insert(
         "Access-Control-Allow-Methods",
@@ -273,21 +444,72 @@ fn apply_cors_headers(res: &mut Response<Body>) {
     );
 }
 
-async fn handle_bridge_request(
+pub(crate) async fn handle_bridge_request(
     req: Request<Body>,
     pending_requests: Arc<PendingMap>,
     main_window: WebviewWindow,
     request_counter: Arc<AtomicU64>,
+    origins: Arc<OriginStore>,
+    session_token: Arc<String>,
+    metrics: Arc<Metrics>,
 ) -> Result<Response<Body>, Infallible> {
+    let origin = req
+        .headers()
+        .get(hyper::header::ORIGIN)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("")
+        .to_string();
+
     if req.method() == hyper::Method::OPTIONS {
         let mut res = Response::new(Body::empty());
-        apply_cors_headers(&mut res);
+        apply_cors_headers(&mut res, &origin);
+        return Ok(res);
+    }
+
+    if origin.is_empty() || !origins.is_approved(&origin) {
+        eprintln!("Rejecting bridge request from unapproved origin: {:?}", origin);
+        metrics.record_denied();
+        let mut res = Response::new(Body::from("Origin not approved"));
+        *res.status_mut() = StatusCode::FORBIDDEN;
+        apply_cors_headers(&mut res, &origin);
+        return Ok(res);
+    }
+
+    let provided_token = req
+        .headers()
+        .get("x-bridge-token")
+        .and_then(|v| v.to_str().ok())
+        .or_else(|| {
+            req.headers()
+                .get(hyper::header::AUTHORIZATION)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.strip_prefix("Bearer "))
+        })
+        .unwrap_or("");
+
+    if provided_token != session_token.as_str() {
+        eprintln!("Rejecting bridge request with missing or invalid auth token");
+        metrics.record_denied();
+        let mut res = Response::new(Body::from("Missing or invalid bridge token"));
+        *res.status_mut() = StatusCode::UNAUTHORIZED;
+        apply_cors_headers(&mut res, &origin);
+        return Ok(res);
+    }
+
+    if req.uri().path() == BRIDGE_METRICS_PATH {
+        let mut res = Response::new(Body::from(metrics.render(pending_requests.len())));
+        res.headers_mut().insert(
+            hyper::header::CONTENT_TYPE,
+            HeaderValue::from_static("text/plain; version=0.0.4"),
+        );
+        apply_cors_headers(&mut res, &origin);
         return Ok(res);
     }
 
     let request_id = request_counter.fetch_add(1, Ordering::Relaxed);
     let method = req.method().clone();
     let uri = req.uri().clone();
+    metrics.record_request(method.as_str(), uri.path(), &origin);
     let headers = req
         .headers()
         .iter()
@@ -303,22 +525,33 @@ async fn handle_bridge_request(
             );
             let mut res = Response::new(Body::from("Failed to read request body"));
             *res.status_mut() = StatusCode::BAD_REQUEST;
-            apply_cors_headers(&mut res);
+            apply_cors_headers(&mut res, &origin);
             return Ok(res);
         }
     };
 
-    let body_str = String::from_utf8_lossy(&whole_body).to_string();
+    let (body_str, body_is_base64) = match String::from_utf8(whole_body.to_vec()) {
+        Ok(text) => (text, false),
+        Err(_) => (BASE64.encode(&whole_body), true),
+    };
 
     let (tx, rx) = oneshot::channel::<TsResponse>();
-    pending_requests.insert(request_id, tx);
+    pending_requests.insert(
+        request_id,
+        PendingEntry {
+            sender: tx,
+            inserted_at: Instant::now(),
+        },
+    );
 
     let event_payload = HttpRequestEvent {
         method: method.to_string(),
         path: uri.to_string(),
         headers,
         body: body_str,
+        body_is_base64,
         request_id,
+        origin: origin.clone(),
     };
 
     let event_json = match serde_json::to_string(&event_payload) {
@@ -331,43 +564,143 @@ async fn handle_bridge_request(
             pending_requests.remove(&request_id);
             let mut res = Response::new(Body::from("Internal Server Error"));
             *res.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
-            apply_cors_headers(&mut res);
+            apply_cors_headers(&mut res, &origin);
             return Ok(res);
         }
     };
 
+    let emit_time = Instant::now();
     if let Err(err) = main_window.emit("http-request", event_json) {
         eprintln!(
             "Failed to emit http-request event for request {}: {:?}",
             request_id, err
         );
         pending_requests.remove(&request_id);
+        metrics.record_error();
         let mut res = Response::new(Body::from("Internal Server Error"));
         *res.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
-        apply_cors_headers(&mut res);
+        apply_cors_headers(&mut res, &origin);
         return Ok(res);
     }
 
-    match rx.await {
-        Ok(ts_response) => {
-            let mut res = Response::new(Body::from(ts_response.body));
-            *res.status_mut() = StatusCode::from_u16(ts_response.status).unwrap_or(StatusCode::OK);
-            apply_cors_headers(&mut res);
+    match tokio::time::timeout(*BRIDGE_REQUEST_TIMEOUT, rx).await {
+        Ok(Ok(ts_response)) => {
+            metrics.observe_latency(emit_time.elapsed());
+            match ts_response.outcome {
+                BridgeOutcome::Denied => metrics.record_denied(),
+                BridgeOutcome::Error => metrics.record_error(),
+                BridgeOutcome::Success => {}
+            }
+            let mut res = match ts_response.outcome {
+                BridgeOutcome::Success => {
+                    let body_bytes: Vec<u8> = if ts_response.body_is_base64 {
+                        match BASE64.decode(&ts_response.body) {
+                            Ok(bytes) => bytes,
+                            Err(err) => {
+                                eprintln!(
+                                    "Failed to decode base64 response body for request {}: {:?}",
+                                    request_id, err
+                                );
+                                let mut res =
+                                    Response::new(Body::from("Invalid base64 response body"));
+                                *res.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                apply_cors_headers(&mut res, &origin);
+                                return Ok(res);
+                            }
+                        }
+                    } else {
+                        ts_response.body.into_bytes()
+                    };
+
+                    let mut res = Response::new(Body::from(body_bytes));
+                    *res.status_mut() =
+                        StatusCode::from_u16(ts_response.status).unwrap_or(StatusCode::OK);
+                    for (name, value) in &ts_response.headers {
+                        if let (Ok(header_name), Ok(header_value)) = (
+                            hyper::header::HeaderName::from_bytes(name.as_bytes()),
+                            HeaderValue::from_str(value),
+                        ) {
+                            res.headers_mut().insert(header_name, header_value);
+                        }
+                    }
+                    res
+                }
+                BridgeOutcome::Denied => {
+                    let body = BridgeErrorBody {
+                        error: "denied",
+                        message: &ts_response.body,
+                    };
+                    let mut res = Response::new(Body::from(
+                        serde_json::to_string(&body).unwrap_or_default(),
+                    ));
+                    *res.status_mut() = StatusCode::FORBIDDEN;
+                    res
+                }
+                BridgeOutcome::Error => {
+                    let body = BridgeErrorBody {
+                        error: "internal_error",
+                        message: &ts_response.body,
+                    };
+                    let mut res = Response::new(Body::from(
+                        serde_json::to_string(&body).unwrap_or_default(),
+                    ));
+                    *res.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                    res
+                }
+            };
+            apply_cors_headers(&mut res, &origin);
             Ok(res)
         }
-        Err(err) => {
+        Ok(Err(err)) => {
             eprintln!(
                 "Error awaiting frontend response for request {}: {:?}",
                 request_id, err
             );
+            metrics.record_timeout();
             let mut res = Response::new(Body::from("Gateway Timeout"));
             *res.status_mut() = StatusCode::GATEWAY_TIMEOUT;
-            apply_cors_headers(&mut res);
+            apply_cors_headers(&mut res, &origin);
+            Ok(res)
+        }
+        Err(_elapsed) => {
+            eprintln!(
+                "Timed out waiting for frontend response for request {}",
+                request_id
+            );
+            pending_requests.remove(&request_id);
+            metrics.record_timeout();
+            let mut res = Response::new(Body::from("Gateway Timeout"));
+            *res.status_mut() = StatusCode::GATEWAY_TIMEOUT;
+            apply_cors_headers(&mut res, &origin);
             Ok(res)
         }
     }
 }
 
+/// Called by the frontend once the user has approved a web page talking to
+/// the wallet bridge; the approval is persisted for future launches.
+#[tauri::command]
+fn approve_bridge_origin(origins: State<Arc<OriginStore>>, origin: String) -> Result<(), String> {
+    origins.approve(origin)
+}
+
+/// A fresh random token minted once per launch; only the bundled frontend
+/// (via `get_bridge_token`) ever learns its value, and it is never logged.
+fn generate_session_token() -> String {
+    rand::thread_rng()
+        .sample_iter(&rand::distributions::Alphanumeric)
+        .take(32)
+        .map(char::from)
+        .collect()
+}
+
+/// Lets the bundled frontend fetch this launch's bridge token so it can
+/// attach it to every request against `https://localhost:2121`.
+#[tauri::command]
+fn get_bridge_token(token: State<Arc<String>>) -> String {
+    (*token).clone()
+}
+
 #[tauri::command]
 fn is_focused(window: Window) -> bool {
     match window.is_focused() {
@@ -378,6 +711,13 @@ fn is_focused(window: Window) -> bool {
 
 #[tauri::command]
 fn request_focus(window: Window) {
+    do_request_focus(&window);
+}
+
+/// Shared body behind the `request_focus` command, split out so the global
+/// hotkey handler can raise the window without going through Tauri's invoke
+/// system.
+fn do_request_focus(window: &Window) {
     #[cfg(target_os = "macos")]
     {
         // Make window visible first - critical for macOS
@@ -477,6 +817,13 @@ fn request_focus(window: Window) {
 /// other tasks. The exact behavior (switch/minimize) differs per platform.
 #[tauri::command]
 fn relinquish_focus(window: Window) {
+    do_relinquish_focus(&window);
+}
+
+/// Shared body behind the `relinquish_focus` command, split out so the
+/// global hotkey handler can dismiss the window without going through
+/// Tauri's invoke system.
+fn do_relinquish_focus(window: &Window) {
     #[cfg(target_os = "linux")]
     {
         // Minimize the window instead of hiding
@@ -662,6 +1009,30 @@ fn main() {
             let pending_requests: Arc<PendingMap> = Arc::new(DashMap::new());
             // Atomic counter to generate unique request IDs.
             let request_counter = Arc::new(AtomicU64::new(1));
+            // Persisted allowlist of origins the user has approved to use the bridge.
+            let origin_store = Arc::new(
+                OriginStore::load(&app.handle()).expect("Failed to load approved origins"),
+            );
+            app.manage(origin_store.clone());
+            // Per-launch bridge auth token; rotates every time the app starts.
+            let session_token = Arc::new(generate_session_token());
+            app.manage(session_token.clone());
+            // Persisted global hotkey that summons/dismisses the approval window.
+            let hotkey_store = Arc::new(
+                HotkeyStore::load(&app.handle()).expect("Failed to load hotkey binding"),
+            );
+            app.manage(hotkey_store.clone());
+            if let Err(err) = register_hotkey(&app.handle(), &hotkey_store.shortcut()) {
+                eprintln!("Failed to register wallet summon hotkey: {}", err);
+            }
+            // Bridge traffic counters/latencies, exposed at `BRIDGE_METRICS_PATH`.
+            let metrics = Arc::new(Metrics::new());
+            // Coordinated graceful-shutdown signal for both bridge servers,
+            // triggered from `RunEvent::ExitRequested` below.
+            let shutdown = Shutdown::new();
+            app.manage(shutdown.clone());
+            // Connection limits/socket options shared by both listeners.
+            let server_tuning = Arc::new(ServerTuning::from_env());
             let tls_state = match ensure_localhost_tls(&app.handle()) {
                 Ok(state) => {
                     println!("Prepared local TLS certificate for https://localhost:2121");
@@ -672,6 +1043,9 @@ fn main() {
                     None
                 }
             };
+            if let Some(tls_state) = &tls_state {
+                app.manage(tls_state.clone());
+            }
 
             {
                 // Set up a listener for "ts-response" events coming from the frontend.
@@ -682,8 +1056,8 @@ fn main() {
                     if payload.len() > 0 {
                         match serde_json::from_str::<TsResponse>(payload) {
                             Ok(ts_response) => {
-                                if let Some((req_id, tx)) = pending_requests.remove(&ts_response.request_id) {
-                                    if let Err(err) = tx.send(ts_response) {
+                                if let Some((req_id, entry)) = pending_requests.remove(&ts_response.request_id) {
+                                    if let Err(err) = entry.sender.send(ts_response) {
                                         eprintln!(
                                             "Failed to send response via oneshot channel for request {}: {:?}",
                                             req_id, err
@@ -707,6 +1081,12 @@ fn main() {
             let main_window_clone = main_window.clone();
             let pending_requests_clone = pending_requests.clone();
             let request_counter_clone = request_counter.clone();
+            let origin_store_clone = origin_store.clone();
+            let session_token_clone = session_token.clone();
+            let metrics_clone = metrics.clone();
+            let shutdown_clone = shutdown.clone();
+            let server_tuning_clone = server_tuning.clone();
+            shutdown.register_listener();
             std::thread::spawn(move || {
                 if let Err(err) = elevate_current_thread_priority() {
                     eprintln!("Unable to raise HTTP runtime bootstrap thread priority: {}", err);
@@ -727,6 +1107,22 @@ fn main() {
                     .expect("Failed to create Tokio runtime");
 
                 rt.block_on(async move {
+                    // Periodically evict pending requests the frontend never answered
+                    // (e.g. the client disconnected before a ts-response arrived).
+                    tokio::spawn({
+                        let pending_requests = pending_requests_clone.clone();
+                        async move {
+                            let mut interval = tokio::time::interval(BRIDGE_REAPER_INTERVAL);
+                            loop {
+                                interval.tick().await;
+                                let now = Instant::now();
+                                pending_requests.retain(|_, entry| {
+                                    now.duration_since(entry.inserted_at) < *BRIDGE_REQUEST_TIMEOUT
+                                });
+                            }
+                        }
+                    });
+
                     // Bind the Hyper server to 127.0.0.1:3321.
                     let addr: SocketAddr = "127.0.0.1:3321".parse().expect("Invalid socket address");
                     println!("HTTP server listening on http://{}", addr);
@@ -734,31 +1130,85 @@ fn main() {
                     // Attempt to bind the server and check for address in use error
                     match Server::try_bind(&addr) {
                         Ok(builder) => {
+                            let builder = builder
+                                .tcp_nodelay(server_tuning_clone.tcp_nodelay)
+                                .tcp_keepalive(server_tuning_clone.tcp_keepalive)
+                                .http1_header_read_timeout(server_tuning_clone.http1_header_read_timeout)
+                                .http1_keepalive(server_tuning_clone.http1_keepalive);
+
+                            // Cap concurrent connections so a buggy or malicious
+                            // local process can't flood `pending_requests`; once
+                            // the cap is hit, new connections get a 503 instead
+                            // of being served.
+                            let connection_limiter =
+                                Arc::new(Semaphore::new(server_tuning_clone.max_connections));
+
                             // Create our Hyper service.
                             let make_svc = make_service_fn(move |_conn| {
                                 // Clone handles for each connection.
                                 let pending_requests = pending_requests_clone.clone();
                                 let main_window = main_window_clone.clone();
                                 let request_counter = request_counter_clone.clone();
+                                let origin_store = origin_store_clone.clone();
+                                let session_token = session_token_clone.clone();
+                                let metrics = metrics_clone.clone();
+                                let connection_limiter = connection_limiter.clone();
 
                                 async move {
-                                    Ok::<_, Infallible>(service_fn(move |req: Request<Body>| {
-                                        handle_bridge_request(
-                                            req,
-                                            pending_requests.clone(),
-                                            main_window.clone(),
-                                            request_counter.clone(),
-                                        )
-                                    }))
+                                    match connection_limiter.try_acquire_owned() {
+                                        Ok(permit) => Ok::<_, Infallible>(service_fn(move |req: Request<Body>| {
+                                            let _permit = &permit;
+                                            handle_bridge_request(
+                                                req,
+                                                pending_requests.clone(),
+                                                main_window.clone(),
+                                                request_counter.clone(),
+                                                origin_store.clone(),
+                                                session_token.clone(),
+                                                metrics.clone(),
+                                            )
+                                        })),
+                                        Err(_) => Ok::<_, Infallible>(service_fn(|_req: Request<Body>| async {
+                                            Ok::<_, Infallible>(
+                                                Response::builder()
+                                                    .status(StatusCode::SERVICE_UNAVAILABLE)
+                                                    .body(Body::from("Too many concurrent connections"))
+                                                    .expect("Failed to build 503 response"),
+                                            )
+                                        })),
+                                    }
                                 }
                             });
 
-                            // Build and run the Hyper server.
-                            let server = builder.serve(make_svc);
+                            // Build and run the Hyper server, stopping once
+                            // `shutdown.trigger()` fires so in-flight requests
+                            // get to finish instead of being cut off.
+                            let shutdown_wait = shutdown_clone.clone();
+                            let server = builder
+                                .serve(make_svc)
+                                .with_graceful_shutdown(
+                                    async move { shutdown_wait.notified().await },
+                                );
 
-                            if let Err(e) = server.await {
-                                eprintln!("Server error: {}", e);
+                            // Bound the drain the same way the HTTPS listener
+                            // does, so a connection that never closes can't
+                            // hang `Shutdown::wait_for_drain` forever.
+                            tokio::pin!(server);
+                            let drain_deadline = async {
+                                shutdown_clone.notified().await;
+                                tokio::time::sleep(SHUTDOWN_DRAIN_TIMEOUT).await;
+                            };
+                            tokio::select! {
+                                result = &mut server => {
+                                    if let Err(e) = result {
+                                        eprintln!("Server error: {}", e);
+                                    }
+                                }
+                                _ = drain_deadline => eprintln!(
+                                    "Timed out waiting for in-flight HTTP requests to drain on shutdown"
+                                ),
                             }
+                            shutdown_clone.listener_drained();
                         }
                         Err(e) => {
                             eprintln!("Failed to bind server: {}", e);
@@ -772,6 +1222,13 @@ fn main() {
                 let main_window_clone = main_window.clone();
                 let pending_requests_clone = pending_requests.clone();
                 let request_counter_clone = request_counter.clone();
+                let origin_store_clone = origin_store.clone();
+                let session_token_clone = session_token.clone();
+                let metrics_clone = metrics.clone();
+                let app_handle_for_reload = app.handle().clone();
+                let shutdown_clone = shutdown.clone();
+                let server_tuning_clone = server_tuning.clone();
+                shutdown.register_listener();
                 std::thread::spawn(move || {
                     if let Err(err) = elevate_current_thread_priority() {
                         eprintln!(
@@ -794,6 +1251,87 @@ fn main() {
                         .expect("Failed to create Tokio runtime");
 
                     rt.block_on(async move {
+                        // Periodically check whether the localhost leaf is due for
+                        // renewal and, if so, swap a fresh one into the live config.
+                        tokio::spawn({
+                            let tls_state = tls_state.clone();
+                            let app_handle = app_handle_for_reload.clone();
+                            async move {
+                                let mut interval = tokio::time::interval(TLS_RELOAD_CHECK_INTERVAL);
+                                loop {
+                                    interval.tick().await;
+                                    match reload_certificate(&app_handle, &tls_state) {
+                                        Ok(true) => {
+                                            println!("Rotated localhost TLS certificate in the background")
+                                        }
+                                        Ok(false) => {}
+                                        Err(err) => eprintln!(
+                                            "Failed to reload localhost TLS certificate: {}",
+                                            err
+                                        ),
+                                    }
+                                }
+                            }
+                        });
+
+                        // Independently watch the cert/key files' mtimes so a
+                        // certificate replaced out-of-band (not through our
+                        // own renewal path above) is still picked up live.
+                        tokio::spawn({
+                            let tls_state = tls_state.clone();
+                            let app_handle = app_handle_for_reload.clone();
+                            async move {
+                                let mut interval = tokio::time::interval(CERT_WATCH_POLL_INTERVAL);
+                                loop {
+                                    interval.tick().await;
+                                    match sync_certificate_from_disk(&app_handle, &tls_state) {
+                                        Ok(true) => {
+                                            println!("Picked up an externally replaced localhost TLS certificate")
+                                        }
+                                        Ok(false) => {}
+                                        Err(err) => eprintln!(
+                                            "Failed to sync localhost TLS certificate from disk: {}",
+                                            err
+                                        ),
+                                    }
+                                }
+                            }
+                        });
+
+                        #[cfg(feature = "quic")]
+                        if let Some(quic_config) = tls_state.quic_server_config.clone() {
+                            for quic_addr in ["127.0.0.1:2121", "[::1]:2121"] {
+                                let quic_addr: SocketAddr =
+                                    quic_addr.parse().expect("Invalid QUIC socket address");
+                                match quic::bind_endpoint((*quic_config).clone(), quic_addr) {
+                                    Ok(endpoint) => {
+                                        println!("QUIC/HTTP-3 listener bound on {}", quic_addr);
+                                        // Route each HTTP/3 request through the same
+                                        // `handle_bridge_request` the HTTP/1.1 and h2
+                                        // listeners use, so origin checks, auth, and
+                                        // metrics stay in one place. Registered with
+                                        // `shutdown` like the other listeners so
+                                        // in-flight HTTP/3 requests also get a drain
+                                        // window on app exit.
+                                        shutdown_clone.register_listener();
+                                        tokio::spawn(quic::run_accept_loop(
+                                            endpoint,
+                                            pending_requests_clone.clone(),
+                                            main_window_clone.clone(),
+                                            request_counter_clone.clone(),
+                                            origin_store_clone.clone(),
+                                            session_token_clone.clone(),
+                                            metrics_clone.clone(),
+                                            shutdown_clone.clone(),
+                                        ));
+                                    }
+                                    Err(err) => {
+                                        eprintln!("Failed to bind QUIC endpoint on {}: {}", quic_addr, err);
+                                    }
+                                }
+                            }
+                        }
+
                         let addr: SocketAddr =
                             "127.0.0.1:2121".parse().expect("Invalid TLS socket address");
                         println!("HTTPS server listening on https://{}", addr);
@@ -808,27 +1346,95 @@ fn main() {
 
                         let tls_acceptor = TlsAcceptor::from(tls_state.server_config.clone());
 
+                        // Join handles for in-flight connections, so we can
+                        // give them a bounded window to finish draining once
+                        // `shutdown.trigger()` stops the accept loop.
+                        let mut connections = Vec::new();
+
+                        // Cap concurrent connections the same way the plain
+                        // HTTP listener does; overflow connections are
+                        // dropped before the (expensive) TLS handshake.
+                        let connection_limiter =
+                            Arc::new(Semaphore::new(server_tuning_clone.max_connections));
+
                         loop {
-                            match listener.accept().await {
+                            tokio::select! {
+                                accepted = listener.accept() => { match accepted {
                                 Ok((stream, _addr)) => {
+                                    if let Err(err) = stream.set_nodelay(server_tuning_clone.tcp_nodelay) {
+                                        eprintln!("Failed to set TCP_NODELAY on HTTPS connection: {}", err);
+                                    }
+
+                                    // `tokio::net::TcpStream` has no keepalive setter of its
+                                    // own; go through a `socket2::SockRef` borrowed from the
+                                    // raw fd/handle to apply the same knob the plain HTTP
+                                    // listener gets for free from hyper's `Server::tcp_keepalive`.
+                                    if let Some(keepalive) = server_tuning_clone.tcp_keepalive {
+                                        let sock_ref = SockRef::from(&stream);
+                                        if let Err(err) =
+                                            sock_ref.set_tcp_keepalive(&TcpKeepalive::new().with_time(keepalive))
+                                        {
+                                            eprintln!("Failed to set TCP keepalive on HTTPS connection: {}", err);
+                                        }
+                                    }
+
+                                    let permit = match connection_limiter.clone().try_acquire_owned() {
+                                        Ok(permit) => permit,
+                                        Err(_) => {
+                                            eprintln!("Rejecting HTTPS connection: too many concurrent connections");
+                                            continue;
+                                        }
+                                    };
+
                                     let tls_acceptor = tls_acceptor.clone();
                                     let pending_requests = pending_requests_clone.clone();
                                     let main_window = main_window_clone.clone();
                                     let request_counter = request_counter_clone.clone();
+                                    let origin_store = origin_store_clone.clone();
+                                    let session_token = session_token_clone.clone();
+                                    let metrics = metrics_clone.clone();
+                                    let server_tuning = server_tuning_clone.clone();
 
-                                    tokio::spawn(async move {
+                                    connections.push(tokio::spawn(async move {
+                                        let _permit = permit;
                                         match tls_acceptor.accept(stream).await {
                                             Ok(tls_stream) => {
+                                                if let Some(peer_certs) =
+                                                    tls_stream.get_ref().1.peer_certificates()
+                                                {
+                                                    if let Some(leaf) = peer_certs.first() {
+                                                        if !tls::verify_peer_is_wallet_client(&leaf.0)
+                                                        {
+                                                            eprintln!(
+                                                                "Rejecting HTTPS connection: client certificate is not our own wallet frontend identity"
+                                                            );
+                                                            return;
+                                                        }
+                                                    }
+                                                }
+
+                                                // ALPN decides whether this connection gets h2
+                                                // (lets one TLS connection multiplex many
+                                                // concurrent bridge calls) or falls back to h1.
+                                                let wants_h2 = tls_stream.get_ref().1.alpn_protocol()
+                                                    == Some(b"h2".as_slice());
+
                                                 let service = service_fn(move |req: Request<Body>| {
                                                     handle_bridge_request(
                                                         req,
                                                         pending_requests.clone(),
                                                         main_window.clone(),
                                                         request_counter.clone(),
+                                                        origin_store.clone(),
+                                                        session_token.clone(),
+                                                        metrics.clone(),
                                                     )
                                                 });
 
                                                 if let Err(err) = Http::new()
+                                                    .http2_only(wants_h2)
+                                                    .http1_header_read_timeout(server_tuning.http1_header_read_timeout)
+                                                    .http1_keepalive(server_tuning.http1_keepalive)
                                                     .serve_connection(tls_stream, service)
                                                     .await
                                                 {
@@ -839,13 +1445,35 @@ fn main() {
                                                 eprintln!("TLS handshake error: {:?}", err);
                                             }
                                         }
-                                    });
+                                    }));
                                 }
                                 Err(err) => {
                                     eprintln!("HTTPS TCP accept error: {}", err);
+                                    tokio::time::sleep(server_tuning_clone.accept_error_backoff).await;
+                                }
                                 }
+                                }
+                                _ = shutdown_clone.notified() => {
+                                    break;
+                                }
+                            }
+                        }
+
+                        // Give already-accepted connections a bounded window
+                        // to finish in-flight bridge requests before we let
+                        // the process exit out from under them.
+                        let drained = tokio::time::timeout(SHUTDOWN_DRAIN_TIMEOUT, async {
+                            for connection in connections {
+                                let _ = connection.await;
                             }
+                        })
+                        .await;
+                        if drained.is_err() {
+                            eprintln!(
+                                "Timed out waiting for in-flight HTTPS requests to drain on shutdown"
+                            );
                         }
+                        shutdown_clone.listener_drained();
                     });
                 });
             } else {
@@ -861,11 +1489,79 @@ fn main() {
         relinquish_focus,
         download,
         save_file,
-        proxy_fetch_manifest
+        proxy_fetch_manifest,
+        export_localhost_cert_p12,
+        approve_bridge_origin,
+        get_bridge_token,
+        reload_tls,
+        set_hotkey
     ])
     .plugin(tauri_plugin_opener::init())
     .plugin(tauri_plugin_dialog::init())
     .plugin(tauri_plugin_updater::Builder::new().build())
-    .run(tauri::generate_context!())
-    .expect("Error while running Tauri application");
+    .plugin(
+        tauri_plugin_global_shortcut::Builder::new()
+            .with_handler(|app, _shortcut, event| {
+                if event.state() != ShortcutState::Pressed {
+                    return;
+                }
+                {
+                    let mut last = LAST_HOTKEY_TOGGLE
+                        .lock()
+                        .expect("hotkey debounce mutex poisoned");
+                    let now = Instant::now();
+                    if let Some(prev) = *last {
+                        if now.duration_since(prev) < HOTKEY_DEBOUNCE {
+                            return;
+                        }
+                    }
+                    *last = Some(now);
+                }
+                if let Some(window) = app.get_webview_window(MAIN_WINDOW_NAME) {
+                    if window.is_focused().unwrap_or(false) {
+                        do_relinquish_focus(&window);
+                    } else {
+                        do_request_focus(&window);
+                    }
+                }
+            })
+            .build(),
+    )
+    .build(tauri::generate_context!())
+    .expect("Error while building Tauri application")
+    .run(|app_handle, event| {
+        // Give in-flight bridge requests (signing, payments) a chance to
+        // finish before the process actually exits. `prevent_exit` holds
+        // Tauri's teardown off; we only let it proceed once every bridge
+        // listener has reported its drain as done (or timed out), since
+        // `shutdown.trigger()` alone just wakes background tasks and does
+        // nothing to stop Tauri tearing the process down right underneath
+        // them.
+        // Set once draining is over, so the `exit(0)` issued below (which
+        // raises `ExitRequested` again) is let through.
+        static DRAIN_DONE: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+        if let tauri::RunEvent::ExitRequested { api, .. } = event {
+            if DRAIN_DONE.load(Ordering::SeqCst) {
+                return;
+            }
+            api.prevent_exit();
+            match app_handle.try_state::<Shutdown>() {
+                Some(shutdown) => {
+                    let shutdown = shutdown.inner().clone();
+                    if shutdown.trigger() {
+                        let app_handle = app_handle.clone();
+                        tauri::async_runtime::spawn(async move {
+                            shutdown.wait_for_drain().await;
+                            DRAIN_DONE.store(true, Ordering::SeqCst);
+                            app_handle.exit(0);
+                        });
+                    }
+                }
+                None => {
+                    DRAIN_DONE.store(true, Ordering::SeqCst);
+                    app_handle.exit(0)
+                }
+            }
+        }
+    });
 }
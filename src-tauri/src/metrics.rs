@@ -0,0 +1,178 @@
+//! In-process counters and a latency histogram for localhost bridge traffic,
+//! rendered in Prometheus text exposition format. Lets us see request volume
+//! by method/path/origin, how long the frontend takes to answer, and how
+//! often requests are denied, time out, or error without restarting the app.
+
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use dashmap::DashMap;
+
+/// Cumulative (`le=`) latency buckets, in seconds, for the request-duration
+/// histogram.
+const LATENCY_BUCKETS_SECONDS: &[f64] = &[0.01, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0];
+
+#[derive(Default)]
+struct Histogram {
+    /// `buckets[i]` counts observations `<= LATENCY_BUCKETS_SECONDS[i]`.
+    buckets: Vec<AtomicU64>,
+    sum_micros: AtomicU64,
+    count: AtomicU64,
+}
+
+impl Histogram {
+    fn new() -> Self {
+        Self {
+            buckets: LATENCY_BUCKETS_SECONDS.iter().map(|_| AtomicU64::new(0)).collect(),
+            sum_micros: AtomicU64::new(0),
+            count: AtomicU64::new(0),
+        }
+    }
+
+    fn observe(&self, duration: Duration) {
+        let seconds = duration.as_secs_f64();
+        for (bucket, bound) in self.buckets.iter().zip(LATENCY_BUCKETS_SECONDS) {
+            if seconds <= *bound {
+                bucket.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        self.sum_micros
+            .fetch_add(duration.as_micros() as u64, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Collects bridge traffic counters/latencies for the lifetime of the app.
+pub struct Metrics {
+    requests_total: DashMap<(String, String, String), AtomicU64>,
+    denied_total: AtomicU64,
+    timeout_total: AtomicU64,
+    error_total: AtomicU64,
+    request_duration: Histogram,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self {
+            requests_total: DashMap::new(),
+            denied_total: AtomicU64::new(0),
+            timeout_total: AtomicU64::new(0),
+            error_total: AtomicU64::new(0),
+            request_duration: Histogram::new(),
+        }
+    }
+
+    /// Record one authorized request by method, first path segment, and origin.
+    pub fn record_request(&self, method: &str, path: &str, origin: &str) {
+        let key = (method.to_string(), path_prefix(path), origin.to_string());
+        self.requests_total
+            .entry(key)
+            .or_insert_with(|| AtomicU64::new(0))
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_denied(&self) {
+        self.denied_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_timeout(&self) {
+        self.timeout_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_error(&self) {
+        self.error_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn observe_latency(&self, duration: Duration) {
+        self.request_duration.observe(duration);
+    }
+
+    /// Render everything in Prometheus text exposition format. `pending` is
+    /// sampled by the caller from the live `PendingMap`, since this module
+    /// doesn't know about bridge-specific types.
+    pub fn render(&self, pending: usize) -> String {
+        let mut out = String::new();
+
+        out.push_str("# HELP bridge_requests_total Authorized bridge requests by method, path, and origin.\n");
+        out.push_str("# TYPE bridge_requests_total counter\n");
+        for entry in self.requests_total.iter() {
+            let (method, path, origin) = entry.key();
+            let count = entry.value().load(Ordering::Relaxed);
+            out.push_str(&format!(
+                "bridge_requests_total{{method=\"{}\",path=\"{}\",origin=\"{}\"}} {}\n",
+                escape_label(method),
+                escape_label(path),
+                escape_label(origin),
+                count
+            ));
+        }
+
+        out.push_str("# HELP bridge_pending_requests Bridge requests awaiting a frontend response.\n");
+        out.push_str("# TYPE bridge_pending_requests gauge\n");
+        out.push_str(&format!("bridge_pending_requests {}\n", pending));
+
+        out.push_str("# HELP bridge_denied_total Bridge requests rejected for an unapproved origin or missing/invalid token, or denied by the user.\n");
+        out.push_str("# TYPE bridge_denied_total counter\n");
+        out.push_str(&format!(
+            "bridge_denied_total {}\n",
+            self.denied_total.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP bridge_timeout_total Bridge requests that timed out waiting for a frontend response.\n");
+        out.push_str("# TYPE bridge_timeout_total counter\n");
+        out.push_str(&format!(
+            "bridge_timeout_total {}\n",
+            self.timeout_total.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP bridge_error_total Bridge requests that resolved as an internal error.\n");
+        out.push_str("# TYPE bridge_error_total counter\n");
+        out.push_str(&format!(
+            "bridge_error_total {}\n",
+            self.error_total.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP bridge_request_duration_seconds Latency from event emit to frontend response.\n");
+        out.push_str("# TYPE bridge_request_duration_seconds histogram\n");
+        for (bound, bucket) in LATENCY_BUCKETS_SECONDS.iter().zip(&self.request_duration.buckets) {
+            out.push_str(&format!(
+                "bridge_request_duration_seconds_bucket{{le=\"{}\"}} {}\n",
+                bound,
+                bucket.load(Ordering::Relaxed)
+            ));
+        }
+        let total_count = self.request_duration.count.load(Ordering::Relaxed);
+        out.push_str(&format!(
+            "bridge_request_duration_seconds_bucket{{le=\"+Inf\"}} {}\n",
+            total_count
+        ));
+
+        let sum_seconds = self.request_duration.sum_micros.load(Ordering::Relaxed) as f64 / 1_000_000.0;
+        out.push_str(&format!(
+            "bridge_request_duration_seconds_sum {}\n",
+            sum_seconds
+        ));
+        out.push_str(&format!(
+            "bridge_request_duration_seconds_count {}\n",
+            total_count
+        ));
+
+        out
+    }
+}
+
+/// First path segment (e.g. `/wallet/action/123` -> `/wallet`), so
+/// high-cardinality IDs in the path don't blow up the label set.
+fn path_prefix(path: &str) -> String {
+    let path = path.split('?').next().unwrap_or(path);
+    match path.trim_start_matches('/').split('/').next() {
+        Some(first) if !first.is_empty() => format!("/{first}"),
+        _ => "/".to_string(),
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -0,0 +1,67 @@
+//! Persisted allowlist of web origins the user has approved to talk to the
+//! localhost bridge. Nothing is trusted until the user has said yes once;
+//! after that the approval sticks across launches.
+
+use std::{
+    collections::HashSet,
+    fs,
+    path::PathBuf,
+    sync::Mutex,
+};
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+const ORIGINS_FILE: &str = "approved-origins.json";
+
+#[derive(Default, Serialize, Deserialize)]
+struct OriginStoreData {
+    approved: HashSet<String>,
+}
+
+pub struct OriginStore {
+    path: PathBuf,
+    data: Mutex<OriginStoreData>,
+}
+
+impl OriginStore {
+    pub fn load(app: &AppHandle) -> Result<Self, String> {
+        let mut dir = app.path().app_local_data_dir().map_err(|e| e.to_string())?;
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+        dir.push(ORIGINS_FILE);
+
+        let data = fs::read_to_string(&dir)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+
+        Ok(Self {
+            path: dir,
+            data: Mutex::new(data),
+        })
+    }
+
+    pub fn is_approved(&self, origin: &str) -> bool {
+        self.data
+            .lock()
+            .expect("origin store mutex poisoned")
+            .approved
+            .contains(origin)
+    }
+
+    /// Approve `origin` for future bridge requests and persist immediately.
+    pub fn approve(&self, origin: String) -> Result<(), String> {
+        {
+            let mut data = self.data.lock().expect("origin store mutex poisoned");
+            data.approved.insert(origin);
+        }
+        self.persist()
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let data = self.data.lock().expect("origin store mutex poisoned");
+        let json = serde_json::to_string_pretty(&*data)
+            .map_err(|e| format!("failed to serialize approved origins: {e}"))?;
+        fs::write(&self.path, json).map_err(|e| format!("failed to persist approved origins: {e}"))
+    }
+}
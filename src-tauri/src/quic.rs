@@ -0,0 +1,237 @@
+//! Optional QUIC/HTTP-3 listener for the localhost bridge, built from the
+//! same rustls `ServerConfig` we already trust for HTTPS. Gated behind the
+//! `quic` feature since it pulls in quinn's (and h3's) own tokio-based
+//! runtime. Every accepted request is routed through the same
+//! `handle_bridge_request` the HTTP/1.1 and h2 listeners use.
+
+use std::{
+    net::SocketAddr,
+    sync::{atomic::AtomicU64, Arc},
+    time::Duration,
+};
+
+use bytes::Buf;
+use h3::{quic::BidiStream, server::RequestStream};
+use quinn::{crypto::rustls::QuicServerConfig, Endpoint, ServerConfig as QuinnServerConfig, TransportConfig};
+use rustls::ServerConfig;
+use tauri::WebviewWindow;
+
+use crate::{
+    handle_bridge_request,
+    metrics::Metrics,
+    origins::OriginStore,
+    shutdown::{Shutdown, SHUTDOWN_DRAIN_TIMEOUT},
+    tls, PendingMap,
+};
+
+const MAX_IDLE_TIMEOUT: Duration = Duration::from_secs(30);
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Build a quinn `ServerConfig` that reuses our existing localhost identity,
+/// so QUIC clients are trusted/rotated by exactly the same cert machinery as
+/// the HTTP/1.1 and HTTP/2 listeners.
+pub fn build_quic_server_config(server_config: Arc<ServerConfig>) -> Result<QuinnServerConfig, String> {
+    // The shared `server_config` advertises ALPN `h2`/`http/1.1` for the
+    // HTTP/1.1+h2 listener; a spec-compliant HTTP/3 client offers `h3`
+    // instead, so reusing that list verbatim makes every QUIC handshake fail
+    // with `no_application_protocol`. Clone the cert/trust config but swap in
+    // the ALPN list HTTP/3 actually negotiates.
+    let mut rustls_config = server_config.as_ref().clone();
+    rustls_config.alpn_protocols = vec![b"h3".to_vec()];
+
+    let quic_crypto: QuicServerConfig = rustls_config
+        .try_into()
+        .map_err(|e| format!("failed to adapt rustls config for QUIC: {e}"))?;
+
+    let mut config = QuinnServerConfig::with_crypto(Arc::new(quic_crypto));
+
+    let mut transport = TransportConfig::default();
+    transport
+        .max_idle_timeout(Some(
+            MAX_IDLE_TIMEOUT
+                .try_into()
+                .map_err(|e| format!("invalid max idle timeout: {e}"))?,
+        ))
+        .keep_alive_interval(Some(KEEP_ALIVE_INTERVAL));
+    config.transport_config(Arc::new(transport));
+
+    Ok(config)
+}
+
+/// Bind a QUIC endpoint on the given loopback address using the shared
+/// server config.
+pub fn bind_endpoint(config: QuinnServerConfig, addr: SocketAddr) -> Result<Endpoint, String> {
+    Endpoint::server(config, addr).map_err(|e| format!("failed to bind QUIC endpoint on {addr}: {e}"))
+}
+
+/// Accept incoming QUIC connections, complete their handshake, verify the
+/// peer's client certificate CN the same way the HTTPS listener does, then
+/// drive HTTP/3 on top of each one, routing every request through the same
+/// `handle_bridge_request` the HTTP/1.1 and h2 listeners use. Stops
+/// accepting new connections once `shutdown.trigger()` fires, then gives
+/// already-accepted connections a bounded window to finish in-flight
+/// requests before reporting its drain as done, the same as the HTTP/1.1+h2
+/// listeners.
+pub async fn run_accept_loop(
+    endpoint: Endpoint,
+    pending_requests: Arc<PendingMap>,
+    main_window: WebviewWindow,
+    request_counter: Arc<AtomicU64>,
+    origins: Arc<OriginStore>,
+    session_token: Arc<String>,
+    metrics: Arc<Metrics>,
+    shutdown: Shutdown,
+) {
+    let mut connections = Vec::new();
+
+    loop {
+        let connecting = tokio::select! {
+            connecting = endpoint.accept() => match connecting {
+                Some(connecting) => connecting,
+                None => break,
+            },
+            _ = shutdown.notified() => break,
+        };
+
+        let pending_requests = pending_requests.clone();
+        let main_window = main_window.clone();
+        let request_counter = request_counter.clone();
+        let origins = origins.clone();
+        let session_token = session_token.clone();
+        let metrics = metrics.clone();
+
+        connections.push(tokio::spawn(async move {
+            let connection = match connecting.await {
+                Ok(connection) => connection,
+                Err(err) => {
+                    eprintln!("QUIC handshake error: {err}");
+                    return;
+                }
+            };
+            println!(
+                "Accepted QUIC connection from {}",
+                connection.remote_address()
+            );
+
+            // Mirrors the HTTPS accept loop's CN check: when mTLS is on, the
+            // peer presented a client cert to satisfy chain validation, but
+            // chain validation alone doesn't confirm it's *our* frontend, so
+            // check its CN against our own client identity too.
+            if let Some(identity) = connection.peer_identity() {
+                if let Ok(certs) = identity.downcast::<Vec<rustls::Certificate>>() {
+                    if let Some(leaf) = certs.first() {
+                        if !tls::verify_peer_is_wallet_client(&leaf.0) {
+                            eprintln!(
+                                "Rejecting QUIC connection: client certificate is not our own wallet frontend identity"
+                            );
+                            return;
+                        }
+                    }
+                }
+            }
+
+            let mut h3_conn =
+                match h3::server::Connection::new(h3_quinn::Connection::new(connection)).await {
+                    Ok(h3_conn) => h3_conn,
+                    Err(err) => {
+                        eprintln!("HTTP/3 handshake error: {err}");
+                        return;
+                    }
+                };
+
+            loop {
+                match h3_conn.accept().await {
+                    Ok(Some((req, stream))) => {
+                        let pending_requests = pending_requests.clone();
+                        let main_window = main_window.clone();
+                        let request_counter = request_counter.clone();
+                        let origins = origins.clone();
+                        let session_token = session_token.clone();
+                        let metrics = metrics.clone();
+
+                        tokio::spawn(async move {
+                            if let Err(err) = serve_h3_request(
+                                req,
+                                stream,
+                                pending_requests,
+                                main_window,
+                                request_counter,
+                                origins,
+                                session_token,
+                                metrics,
+                            )
+                            .await
+                            {
+                                eprintln!("HTTP/3 request error: {err}");
+                            }
+                        });
+                    }
+                    Ok(None) => break,
+                    Err(err) => {
+                        eprintln!("HTTP/3 connection error: {err}");
+                        break;
+                    }
+                }
+            }
+        }));
+    }
+
+    let drained = tokio::time::timeout(SHUTDOWN_DRAIN_TIMEOUT, async {
+        for connection in connections {
+            let _ = connection.await;
+        }
+    })
+    .await;
+    if drained.is_err() {
+        eprintln!("Timed out waiting for in-flight QUIC requests to drain on shutdown");
+    }
+    shutdown.listener_drained();
+}
+
+/// Read one HTTP/3 request to completion, dispatch it through
+/// `handle_bridge_request`, and write the response back on the same stream.
+/// Bridge payloads (signing/payment requests) are small, so buffering the
+/// whole body up front is simpler than threading a streaming `Body` through
+/// h3's `RequestStream`.
+async fn serve_h3_request<S>(
+    req: http::Request<()>,
+    mut stream: RequestStream<S, bytes::Bytes>,
+    pending_requests: Arc<PendingMap>,
+    main_window: WebviewWindow,
+    request_counter: Arc<AtomicU64>,
+    origins: Arc<OriginStore>,
+    session_token: Arc<String>,
+    metrics: Arc<Metrics>,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    S: BidiStream<bytes::Bytes>,
+{
+    let mut body = Vec::new();
+    while let Some(mut chunk) = stream.recv_data().await? {
+        let mut buf = vec![0u8; chunk.remaining()];
+        chunk.copy_to_slice(&mut buf);
+        body.extend_from_slice(&buf);
+    }
+
+    let req = req.map(|_| hyper::Body::from(body));
+    let response = handle_bridge_request(
+        req,
+        pending_requests,
+        main_window,
+        request_counter,
+        origins,
+        session_token,
+        metrics,
+    )
+    .await
+    .unwrap();
+
+    let (parts, body) = response.into_parts();
+    stream
+        .send_response(http::Response::from_parts(parts, ()))
+        .await?;
+    let body = hyper::body::to_bytes(body).await?;
+    stream.send_data(body).await?;
+    stream.finish().await?;
+    Ok(())
+}
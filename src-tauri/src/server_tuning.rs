@@ -0,0 +1,55 @@
+//! Connection limits and socket options shared by both bridge listeners, so
+//! a buggy or malicious local process opening many connections can't flood
+//! `pending_requests` or the Tauri event channel.
+
+use std::time::Duration;
+
+const MAX_CONNECTIONS_ENV: &str = "USER_WALLET_MAX_CONNECTIONS";
+
+/// Per-listener tuning knobs. Defaults are conservative enough for normal
+/// single-frontend use while still tolerating a handful of concurrent
+/// requests; operators can raise or lower the connection cap via
+/// `USER_WALLET_MAX_CONNECTIONS` without a rebuild.
+pub struct ServerTuning {
+    /// Connections served concurrently, per listener, before new ones are
+    /// turned away rather than queued indefinitely: the plain HTTP listener
+    /// answers them with a 503, the HTTPS listener drops them before the TLS
+    /// handshake.
+    pub max_connections: usize,
+    pub tcp_nodelay: bool,
+    pub tcp_keepalive: Option<Duration>,
+    pub http1_header_read_timeout: Duration,
+    pub http1_keepalive: bool,
+    /// How long to pause after a transient `accept()` error before retrying,
+    /// so a burst of failures doesn't spin the loop hot.
+    pub accept_error_backoff: Duration,
+}
+
+impl Default for ServerTuning {
+    fn default() -> Self {
+        Self {
+            max_connections: 256,
+            tcp_nodelay: true,
+            tcp_keepalive: Some(Duration::from_secs(60)),
+            http1_header_read_timeout: Duration::from_secs(10),
+            http1_keepalive: true,
+            accept_error_backoff: Duration::from_millis(100),
+        }
+    }
+}
+
+impl ServerTuning {
+    /// Build tuning from optional environment overrides, falling back to the
+    /// defaults above for anything unset or unparsable.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            max_connections: env_usize(MAX_CONNECTIONS_ENV).unwrap_or(defaults.max_connections),
+            ..defaults
+        }
+    }
+}
+
+fn env_usize(key: &str) -> Option<usize> {
+    std::env::var(key).ok()?.parse().ok()
+}
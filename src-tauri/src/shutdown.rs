@@ -0,0 +1,96 @@
+//! Coordinated graceful-shutdown signal shared by both bridge servers, so a
+//! single `trigger()` call (from Tauri's `RunEvent::ExitRequested`) lets
+//! in-flight requests finish instead of having their sockets reset out from
+//! under them when the app quits.
+
+use std::sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    Arc,
+};
+
+use tokio::sync::Notify;
+
+/// How long the HTTPS accept loop waits for spawned connections to finish
+/// draining before giving up and returning anyway.
+pub const SHUTDOWN_DRAIN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Fires its notification exactly once; every call after the first is a
+/// no-op, so repeated exit requests can't race each other into notifying
+/// twice.
+#[derive(Clone)]
+pub struct Shutdown {
+    notify: Arc<Notify>,
+    triggered: Arc<AtomicBool>,
+    active_listeners: Arc<AtomicUsize>,
+    drained: Arc<Notify>,
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        Self {
+            notify: Arc::new(Notify::new()),
+            triggered: Arc::new(AtomicBool::new(false)),
+            active_listeners: Arc::new(AtomicUsize::new(0)),
+            drained: Arc::new(Notify::new()),
+        }
+    }
+
+    /// Signal every waiting server task to begin draining. Safe to call more
+    /// than once; only the first call has any effect. Returns `true` the
+    /// first time it's called (so the caller knows it's the one responsible
+    /// for waiting out the drain), `false` on every call after.
+    pub fn trigger(&self) -> bool {
+        let first = !self.triggered.swap(true, Ordering::SeqCst);
+        if first {
+            self.notify.notify_waiters();
+        }
+        first
+    }
+
+    /// Resolves once `trigger()` has been called, even if that happened
+    /// before this was first awaited.
+    ///
+    /// Follows the check-enable-recheck-await sequence `Notify` itself
+    /// documents for this exact "fire once, maybe before anyone's
+    /// listening" idiom: if we registered as a waiter and `trigger()` raced
+    /// us right after, `notify_waiters()` still reaches us because we were
+    /// already enabled, instead of firing into an empty waiter list.
+    pub async fn notified(&self) {
+        let notified = self.notify.notified();
+        tokio::pin!(notified);
+        notified.as_mut().enable();
+        if self.triggered.load(Ordering::SeqCst) {
+            return;
+        }
+        notified.await;
+    }
+
+    /// Call once per bridge listener (HTTP, HTTPS) as it starts up, so
+    /// `wait_for_drain` knows how many listeners still need to finish
+    /// draining before the app can actually exit.
+    pub fn register_listener(&self) {
+        self.active_listeners.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Call once a listener has finished draining its in-flight connections
+    /// (or given up after `SHUTDOWN_DRAIN_TIMEOUT`). Wakes `wait_for_drain`
+    /// once every registered listener has reported in.
+    pub fn listener_drained(&self) {
+        if self.active_listeners.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.drained.notify_waiters();
+        }
+    }
+
+    /// Resolves once every registered listener has called
+    /// `listener_drained`. Same enable-before-check sequence as `notified`
+    /// to avoid missing a wakeup that lands between the check and the await.
+    pub async fn wait_for_drain(&self) {
+        let drained = self.drained.notified();
+        tokio::pin!(drained);
+        drained.as_mut().enable();
+        if self.active_listeners.load(Ordering::SeqCst) == 0 {
+            return;
+        }
+        drained.await;
+    }
+}
@@ -4,12 +4,27 @@ use std::{
     net::{IpAddr, Ipv4Addr, Ipv6Addr},
     path::{Path, PathBuf},
     process::Command,
-    sync::Arc,
+    sync::{Arc, Mutex},
+    time::SystemTime,
 };
 
+use arc_swap::ArcSwap;
+use rand::Rng;
 use rcgen::{Certificate, CertificateParams, DistinguishedName, DnType, IsCa, KeyPair, SanType};
-use rustls::{Certificate as RustlsCertificate, PrivateKey, ServerConfig};
+use rustls::server::{AllowAnyAuthenticatedClient, ClientHello, ResolvesServerCert};
+use rustls::sign::CertifiedKey;
+use rustls::{Certificate as RustlsCertificate, PrivateKey, RootCertStore, ServerConfig};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fmt;
 use tauri::{AppHandle, Manager};
+use x509_parser::prelude::{FromDer, X509Certificate};
+
+/// Advertised in order of preference so a negotiating client (or our own
+/// HTTPS accept loop, which dispatches on whichever one won) can multiplex
+/// concurrent bridge calls over h2 when it's able to, falling back to h1.
+const ALPN_PROTOCOLS: &[&[u8]] = &[b"h2", b"http/1.1"];
 
 const CERT_LABEL: &str = "User Wallet Localhost";
 // Legacy labels we previously used; keep them so we don't keep re-adding the cert on macOS
@@ -18,8 +33,106 @@ const CERT_FILE: &str = "metanet-localhost.pem";
 const KEY_FILE: &str = "metanet-localhost-key.pem";
 const CERT_DER_FILE: &str = "metanet-localhost.der";
 
+/// Opt-in via `USER_WALLET_REQUIRE_MTLS=1`. Off by default so existing
+/// installs that only trust the server leaf keep working unchanged.
+const REQUIRE_MTLS_ENV: &str = "USER_WALLET_REQUIRE_MTLS";
+
+const CLIENT_CA_FILE: &str = "metanet-localhost-client-ca.pem";
+const CLIENT_CA_KEY_FILE: &str = "metanet-localhost-client-ca-key.pem";
+const CLIENT_CERT_FILE: &str = "metanet-localhost-client.pem";
+const CLIENT_KEY_FILE: &str = "metanet-localhost-client-key.pem";
+const CLIENT_CERT_EXPIRY_FILE: &str = "metanet-localhost-client.expiry";
+
+/// How long the wallet frontend's own client certificate is valid for. Kept
+/// much shorter than the server leaf since it's minted and consumed entirely
+/// locally and never needs to be re-trusted out-of-band.
+const CLIENT_CERT_VALIDITY_DAYS: i64 = 7;
+/// Regenerate the client leaf once it's within this many days of expiring.
+const CLIENT_CERT_RENEWAL_WINDOW_DAYS: i64 = 2;
+/// The local client CA lives much longer than the leaves it signs; rotating
+/// it would invalidate the `RootCertStore` every already-running listener
+/// captured at startup, so only the leaf it signs ever gets renewed.
+const CLIENT_CA_VALIDITY_DAYS: i64 = 825;
+
+const CERT_P12_FILE: &str = "metanet-localhost.p12";
+const CERT_EXPIRY_FILE: &str = "metanet-localhost.expiry";
+/// How long a freshly minted leaf certificate is valid for.
+const CERT_VALIDITY_DAYS: i64 = 90;
+/// Regenerate once the leaf is within this many days of expiring, so we
+/// never serve a cert that's about to stop validating mid-session.
+const CERT_RENEWAL_WINDOW_DAYS: i64 = 14;
+
+/// Auditable record of every SubjectPublicKeyInfo we've ever issued for the
+/// localhost leaf, keyed by its SHA-256 hash, so a rotated cert can never be
+/// silently re-trusted.
+const SPKI_LEDGER_FILE: &str = "metanet-localhost-spki-ledger.json";
+
 pub struct LocalhostTls {
     pub server_config: Arc<ServerConfig>,
+    /// The resolver backing `server_config`'s cert; swapping it in place lets
+    /// a rotated leaf take effect for new handshakes without rebuilding the
+    /// `TlsAcceptor` or restarting the listener.
+    cert_resolver: Arc<SwappableCertResolver>,
+    /// Modification times of the cert/key files as of the last time we loaded
+    /// them, so `sync_certificate_from_disk` can tell an out-of-band
+    /// replacement (e.g. an admin dropping in a new cert by hand) apart from
+    /// an unchanged file.
+    watched_mtimes: Mutex<(Option<SystemTime>, Option<SystemTime>)>,
+    /// Present only when mTLS is enabled; the frontend's own client identity,
+    /// so it can present it back to us on each connection.
+    pub client_identity: Option<ClientIdentity>,
+    /// Quinn config for the optional QUIC/HTTP-3 listener, built from the
+    /// same `server_config` above so certs/trust stay in one place.
+    #[cfg(feature = "quic")]
+    pub quic_server_config: Option<Arc<quinn::ServerConfig>>,
+    /// Per-store outcome of the most recent trust-store installation pass.
+    pub trust_report: TrustReport,
+}
+
+/// A `ResolvesServerCert` whose key can be swapped in place, so a rotated
+/// leaf certificate can be picked up by new handshakes without rebuilding the
+/// `ServerConfig`/`TlsAcceptor` that already-running listeners hold onto.
+/// In-flight handshakes that already grabbed the old `Arc<CertifiedKey>`
+/// finish with it; only handshakes that start afterwards see the new one.
+struct SwappableCertResolver {
+    current: ArcSwap<CertifiedKey>,
+}
+
+impl SwappableCertResolver {
+    fn new(certified_key: CertifiedKey) -> Self {
+        Self {
+            current: ArcSwap::from_pointee(certified_key),
+        }
+    }
+
+    fn swap(&self, certified_key: CertifiedKey) {
+        self.current.store(Arc::new(certified_key));
+    }
+}
+
+impl fmt::Debug for SwappableCertResolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SwappableCertResolver").finish()
+    }
+}
+
+impl ResolvesServerCert for SwappableCertResolver {
+    fn resolve(&self, _client_hello: ClientHello) -> Option<Arc<CertifiedKey>> {
+        Some(self.current.load_full())
+    }
+}
+
+/// Build the `CertifiedKey` rustls needs to serve `paths`' current leaf.
+fn certified_key_for(paths: &CertPaths) -> Result<CertifiedKey, String> {
+    let (cert_chain, key) = read_cert_and_key(paths)?;
+    let signing_key =
+        rustls::sign::any_supported_type(&key).map_err(|e| format!("unsupported private key: {e}"))?;
+    Ok(CertifiedKey::new(cert_chain, signing_key))
+}
+
+pub struct ClientIdentity {
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
 }
 
 #[derive(Clone)]
@@ -27,37 +140,304 @@ struct CertPaths {
     cert_path: PathBuf,
     key_path: PathBuf,
     cert_der_path: PathBuf,
+    expiry_path: PathBuf,
+    ledger_path: PathBuf,
 }
 
-pub fn ensure_localhost_tls(app: &AppHandle) -> Result<LocalhostTls, String> {
+#[derive(Clone)]
+struct ClientAuthPaths {
+    ca_cert_path: PathBuf,
+    ca_key_path: PathBuf,
+    client_cert_path: PathBuf,
+    client_key_path: PathBuf,
+    client_expiry_path: PathBuf,
+}
+
+fn cert_dir(app: &AppHandle) -> Result<PathBuf, String> {
     let mut cert_dir = app.path().app_local_data_dir().map_err(|e| e.to_string())?;
     cert_dir.push("certificates");
     fs::create_dir_all(&cert_dir).map_err(|e| e.to_string())?;
+    Ok(cert_dir)
+}
+
+fn cert_paths(cert_dir: &Path) -> CertPaths {
+    CertPaths {
+        cert_path: cert_dir.join(CERT_FILE),
+        key_path: cert_dir.join(KEY_FILE),
+        cert_der_path: cert_dir.join(CERT_DER_FILE),
+        expiry_path: cert_dir.join(CERT_EXPIRY_FILE),
+        ledger_path: cert_dir.join(SPKI_LEDGER_FILE),
+    }
+}
 
-    let cert_path = cert_dir.join(CERT_FILE);
-    let key_path = cert_dir.join(KEY_FILE);
-    let cert_der_path = cert_dir.join(CERT_DER_FILE);
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum SpkiStatus {
+    Current,
+    Revoked,
+}
 
-    let paths = CertPaths {
-        cert_path: cert_path.clone(),
-        key_path: key_path.clone(),
-        cert_der_path: cert_der_path.clone(),
-    };
+#[derive(Default, Serialize, Deserialize)]
+struct SpkiLedger {
+    /// SHA-256 (hex) of SubjectPublicKeyInfo -> status.
+    entries: HashMap<String, SpkiStatus>,
+}
+
+impl SpkiLedger {
+    fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self, path: &Path) -> Result<(), String> {
+        let json =
+            serde_json::to_string_pretty(self).map_err(|e| format!("failed to serialize SPKI ledger: {e}"))?;
+        fs::write(path, json).map_err(|e| format!("failed to persist SPKI ledger: {e}"))
+    }
+
+    fn is_revoked(&self, spki_hash: &str) -> bool {
+        matches!(self.entries.get(spki_hash), Some(SpkiStatus::Revoked))
+    }
+
+    /// Mark every previously-current entry as revoked and record `spki_hash`
+    /// as the new current one, returning true if any entry was newly revoked
+    /// (i.e. this is an actual rotation, not the very first cert).
+    fn rotate_to(&mut self, spki_hash: String) -> bool {
+        let mut revoked_something = false;
+        for (hash, status) in self.entries.iter_mut() {
+            if *status == SpkiStatus::Current && hash != &spki_hash {
+                *status = SpkiStatus::Revoked;
+                revoked_something = true;
+            }
+        }
+        self.entries.insert(spki_hash, SpkiStatus::Current);
+        revoked_something
+    }
+}
+
+/// SHA-256 of the SubjectPublicKeyInfo carried by a DER-encoded certificate.
+fn spki_sha256_hex(cert_der: &[u8]) -> Result<String, String> {
+    let (_, cert) =
+        X509Certificate::from_der(cert_der).map_err(|e| format!("failed to parse certificate der: {e}"))?;
+    let mut hasher = Sha256::new();
+    hasher.update(cert.public_key().raw);
+    Ok(bytes_to_hex(&hasher.finalize()))
+}
+
+fn bytes_to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+pub fn ensure_localhost_tls(app: &AppHandle) -> Result<LocalhostTls, String> {
+    let cert_dir = cert_dir(app)?;
+    let paths = cert_paths(&cert_dir);
+    let cert_path = paths.cert_path.clone();
+    let key_path = paths.key_path.clone();
 
     let mut newly_created = false;
+    let mut rotated = false;
     if !cert_path.exists() || !key_path.exists() {
-        generate_certificate(&paths)?;
+        rotated = generate_certificate(&paths)?;
+        newly_created = true;
+    } else if cert_needs_renewal(&paths) {
+        rotated = generate_certificate(&paths)?;
         newly_created = true;
     }
 
-    trust_certificate(&paths, newly_created)?;
+    if rotated {
+        let mut report = TrustReport::default();
+        revoke_stale_trust(&paths, &mut report);
+        for (store, outcome) in &report.entries {
+            if let TrustOutcome::Failed { detail } = outcome {
+                eprintln!("Failed to revoke stale localhost certificate in {store}: {detail}");
+            }
+        }
+    }
+
+    // A leaf whose SPKI shows up as revoked in our own ledger (e.g. a
+    // tampered or restored-from-backup cert file) must never be served.
+    let leaf_der =
+        fs::read(&paths.cert_der_path).map_err(|e| format!("failed to read certificate der: {e}"))?;
+    let leaf_spki = spki_sha256_hex(&leaf_der)?;
+    if SpkiLedger::load(&paths.ledger_path).is_revoked(&leaf_spki) {
+        return Err("refusing to load TLS config: leaf certificate's SPKI is revoked".into());
+    }
+
+    let trust_report = trust_certificate(&paths, newly_created)?;
+    for (store, outcome) in &trust_report.entries {
+        match outcome {
+            TrustOutcome::Failed { detail } => {
+                eprintln!("Failed to trust localhost certificate in {store}: {detail}")
+            }
+            TrustOutcome::Trusted => println!("Trusted localhost certificate in {store}"),
+            TrustOutcome::AlreadyTrusted | TrustOutcome::Skipped => {}
+        }
+    }
+
+    let require_mtls = env::var(REQUIRE_MTLS_ENV)
+        .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false);
+
+    let initial_mtimes = file_mtimes(&paths);
+
+    if require_mtls {
+        let client_auth_paths = ClientAuthPaths {
+            ca_cert_path: cert_dir.join(CLIENT_CA_FILE),
+            ca_key_path: cert_dir.join(CLIENT_CA_KEY_FILE),
+            client_cert_path: cert_dir.join(CLIENT_CERT_FILE),
+            client_key_path: cert_dir.join(CLIENT_KEY_FILE),
+            client_expiry_path: cert_dir.join(CLIENT_CERT_EXPIRY_FILE),
+        };
+
+        if !client_auth_paths.ca_cert_path.exists() || !client_auth_paths.client_cert_path.exists()
+        {
+            generate_client_identity(&client_auth_paths)?;
+        } else if client_cert_needs_renewal(&client_auth_paths) {
+            rotate_client_certificate(&client_auth_paths)?;
+        }
+
+        let cert_resolver = Arc::new(SwappableCertResolver::new(certified_key_for(&paths)?));
+        let server_config = load_rustls_config_with_mtls(&client_auth_paths, cert_resolver.clone())?;
+        #[cfg(feature = "quic")]
+        let quic_server_config = build_quic_server_config(&server_config);
+
+        Ok(LocalhostTls {
+            server_config,
+            cert_resolver,
+            watched_mtimes: Mutex::new(initial_mtimes),
+            client_identity: Some(ClientIdentity {
+                cert_path: client_auth_paths.client_cert_path,
+                key_path: client_auth_paths.client_key_path,
+            }),
+            #[cfg(feature = "quic")]
+            quic_server_config,
+            trust_report,
+        })
+    } else {
+        let cert_resolver = Arc::new(SwappableCertResolver::new(certified_key_for(&paths)?));
+        let server_config = load_rustls_config(cert_resolver.clone())?;
+        #[cfg(feature = "quic")]
+        let quic_server_config = build_quic_server_config(&server_config);
+
+        Ok(LocalhostTls {
+            server_config,
+            cert_resolver,
+            watched_mtimes: Mutex::new(initial_mtimes),
+            client_identity: None,
+            #[cfg(feature = "quic")]
+            quic_server_config,
+            trust_report,
+        })
+    }
+}
+
+/// Regenerate the localhost leaf if it's due for renewal and atomically swap
+/// it into `tls`'s live `ServerConfig`, so a long-running session never needs
+/// a restart to pick up a fresh certificate. Also rotates the short-lived
+/// mTLS client leaf if mTLS is enabled and it's due, independently of
+/// whether the server leaf itself needed renewing this time. Returns true
+/// if anything was actually rotated.
+pub fn reload_certificate(app: &AppHandle, tls: &LocalhostTls) -> Result<bool, String> {
+    let cert_dir = cert_dir(app)?;
+    let paths = cert_paths(&cert_dir);
+
+    let mut reloaded = false;
+
+    if cert_needs_renewal(&paths) {
+        let rotated = generate_certificate(&paths)?;
+        if rotated {
+            let mut report = TrustReport::default();
+            revoke_stale_trust(&paths, &mut report);
+            for (store, outcome) in &report.entries {
+                if let TrustOutcome::Failed { detail } = outcome {
+                    eprintln!("Failed to revoke stale localhost certificate in {store}: {detail}");
+                }
+            }
+        }
+
+        let trust_report = trust_certificate(&paths, true)?;
+        for (store, outcome) in &trust_report.entries {
+            if let TrustOutcome::Failed { detail } = outcome {
+                eprintln!("Failed to trust reloaded localhost certificate in {store}: {detail}");
+            }
+        }
+
+        tls.cert_resolver.swap(certified_key_for(&paths)?);
+        *tls
+            .watched_mtimes
+            .lock()
+            .expect("watched mtimes mutex poisoned") = file_mtimes(&paths);
+        reloaded = true;
+    }
+
+    if tls.client_identity.is_some() {
+        let client_auth_paths = ClientAuthPaths {
+            ca_cert_path: cert_dir.join(CLIENT_CA_FILE),
+            ca_key_path: cert_dir.join(CLIENT_CA_KEY_FILE),
+            client_cert_path: cert_dir.join(CLIENT_CERT_FILE),
+            client_key_path: cert_dir.join(CLIENT_KEY_FILE),
+            client_expiry_path: cert_dir.join(CLIENT_CERT_EXPIRY_FILE),
+        };
+        if client_cert_needs_renewal(&client_auth_paths) {
+            rotate_client_certificate(&client_auth_paths)?;
+            reloaded = true;
+        }
+    }
+
+    Ok(reloaded)
+}
+
+fn file_mtimes(paths: &CertPaths) -> (Option<SystemTime>, Option<SystemTime>) {
+    let mtime = |path: &Path| fs::metadata(path).and_then(|m| m.modified()).ok();
+    (mtime(&paths.cert_path), mtime(&paths.key_path))
+}
+
+/// Watch the cert/key files for an out-of-band replacement (as opposed to a
+/// rotation `reload_certificate` triggered itself) and, if their modification
+/// times have moved since the last check, parse the new PEM and swap it into
+/// the live resolver. New handshakes pick up the change immediately; already
+/// established connections are undisturbed. Returns true if a swap happened.
+pub fn sync_certificate_from_disk(app: &AppHandle, tls: &LocalhostTls) -> Result<bool, String> {
+    let cert_dir = cert_dir(app)?;
+    let paths = cert_paths(&cert_dir);
+    let current_mtimes = file_mtimes(&paths);
+
+    {
+        let watched = tls
+            .watched_mtimes
+            .lock()
+            .expect("watched mtimes mutex poisoned");
+        if *watched == current_mtimes {
+            return Ok(false);
+        }
+    }
 
-    let server_config = load_rustls_config(&paths)?;
+    let certified_key = certified_key_for(&paths)?;
+    tls.cert_resolver.swap(certified_key);
+    *tls
+        .watched_mtimes
+        .lock()
+        .expect("watched mtimes mutex poisoned") = current_mtimes;
+    Ok(true)
+}
 
-    Ok(LocalhostTls { server_config })
+#[cfg(feature = "quic")]
+fn build_quic_server_config(server_config: &Arc<ServerConfig>) -> Option<Arc<quinn::ServerConfig>> {
+    match crate::quic::build_quic_server_config(server_config.clone()) {
+        Ok(config) => Some(Arc::new(config)),
+        Err(err) => {
+            eprintln!("Failed to build QUIC server config, HTTP/3 listener disabled: {err}");
+            None
+        }
+    }
 }
 
-fn generate_certificate(paths: &CertPaths) -> Result<(), String> {
+/// Generate a fresh leaf cert/key pair and record it in the SPKI ledger.
+/// Returns true if this rotated out a previously-current cert (i.e. this is
+/// a renewal rather than the very first cert this install has minted).
+fn generate_certificate(paths: &CertPaths) -> Result<bool, String> {
     let mut params = CertificateParams::new(vec!["localhost".to_string()]);
     params.subject_alt_names = vec![
         SanType::DnsName("localhost".into()),
@@ -71,6 +451,13 @@ fn generate_certificate(paths: &CertPaths) -> Result<(), String> {
     distinguished_name.push(DnType::CommonName, CERT_LABEL);
     params.distinguished_name = distinguished_name;
 
+    // A small amount of backdating tolerates clock skew between the machine
+    // that minted the cert and whatever verifies it moments later.
+    let not_before = time::OffsetDateTime::now_utc() - time::Duration::hours(1);
+    let not_after = time::OffsetDateTime::now_utc() + time::Duration::days(CERT_VALIDITY_DAYS);
+    params.not_before = not_before;
+    params.not_after = not_after;
+
     let key_pair = KeyPair::generate(&rcgen::PKCS_ECDSA_P256_SHA256)
         .map_err(|e| format!("failed to generate keypair: {e}"))?;
     params.key_pair = Some(key_pair);
@@ -86,16 +473,253 @@ fn generate_certificate(paths: &CertPaths) -> Result<(), String> {
         .serialize_der()
         .map_err(|e| format!("failed to serialize certificate der: {e}"))?;
 
+    let spki_hash = spki_sha256_hex(&cert_der)?;
+
     fs::write(&paths.cert_path, cert_pem)
         .map_err(|e| format!("failed to write certificate: {e}"))?;
     fs::write(&paths.key_path, key_pem).map_err(|e| format!("failed to write private key: {e}"))?;
     fs::write(&paths.cert_der_path, cert_der)
         .map_err(|e| format!("failed to write certificate der: {e}"))?;
 
+    let not_after_str = not_after
+        .format(&time::format_description::well_known::Rfc3339)
+        .map_err(|e| format!("failed to format certificate expiry: {e}"))?;
+    fs::write(&paths.expiry_path, not_after_str)
+        .map_err(|e| format!("failed to persist certificate expiry: {e}"))?;
+
+    let mut ledger = SpkiLedger::load(&paths.ledger_path);
+    let rotated = ledger.rotate_to(spki_hash);
+    ledger.save(&paths.ledger_path)?;
+
+    Ok(rotated)
+}
+
+/// True if the current leaf is already expired or will expire within the
+/// renewal window. Falls back to parsing the DER cert (via x509-parser) when
+/// the persisted expiry sidecar file is missing, e.g. for installs that
+/// generated their cert before this check existed.
+fn cert_needs_renewal(paths: &CertPaths) -> bool {
+    let not_after = match fs::read_to_string(&paths.expiry_path)
+        .ok()
+        .and_then(|s| time::OffsetDateTime::parse(&s, &time::format_description::well_known::Rfc3339).ok())
+    {
+        Some(parsed) => Some(parsed),
+        None => read_leaf_expiry(&paths.cert_der_path),
+    };
+
+    match not_after {
+        Some(not_after) => {
+            let renew_by = not_after - time::Duration::days(CERT_RENEWAL_WINDOW_DAYS);
+            time::OffsetDateTime::now_utc() >= renew_by
+        }
+        // If we can't determine expiry at all, don't force a surprise
+        // regeneration; `trust_certificate` will still run against what we have.
+        None => false,
+    }
+}
+
+fn read_leaf_expiry(cert_der_path: &Path) -> Option<time::OffsetDateTime> {
+    let der = fs::read(cert_der_path).ok()?;
+    let (_, cert) = X509Certificate::from_der(&der).ok()?;
+    time::OffsetDateTime::from_unix_timestamp(cert.validity().not_after.timestamp()).ok()
+}
+
+/// Export the generated localhost identity (cert + private key) as a
+/// password-protected PKCS#12 bundle next to `CERT_DER_FILE`, for tools and
+/// keychains that only accept `.p12`/`.pfx`. Returns the bundle's path and
+/// the passphrase that protects it (a random one is minted if none is
+/// supplied).
+pub fn export_pkcs12(app: &AppHandle, passphrase: Option<String>) -> Result<(PathBuf, String), String> {
+    let cert_dir = cert_dir(app)?;
+    let paths = cert_paths(&cert_dir);
+
+    if !paths.cert_der_path.exists() || !paths.key_path.exists() {
+        return Err("no localhost certificate has been generated yet".into());
+    }
+
+    let passphrase = passphrase.unwrap_or_else(generate_random_passphrase);
+
+    let cert_der =
+        fs::read(&paths.cert_der_path).map_err(|e| format!("failed to read certificate der: {e}"))?;
+
+    let key_file =
+        fs::File::open(&paths.key_path).map_err(|e| format!("failed to open private key: {e}"))?;
+    let mut key_reader = BufReader::new(key_file);
+    let key_der = rustls_pemfile::pkcs8_private_keys(&mut key_reader)
+        .map_err(|e| format!("failed to parse private key: {e}"))?
+        .into_iter()
+        .next()
+        .ok_or_else(|| "no private key found".to_string())?;
+
+    let pfx = p12::PFX::new(&cert_der, &key_der, None, &passphrase, CERT_LABEL)
+        .ok_or_else(|| "failed to build pkcs12 bundle".to_string())?;
+    let p12_der = pfx.to_der();
+
+    let p12_path = cert_dir.join(CERT_P12_FILE);
+    fs::write(&p12_path, p12_der).map_err(|e| format!("failed to write pkcs12 bundle: {e}"))?;
+
+    Ok((p12_path, passphrase))
+}
+
+fn generate_random_passphrase() -> String {
+    rand::thread_rng()
+        .sample_iter(&rand::distributions::Alphanumeric)
+        .take(24)
+        .map(char::from)
+        .collect()
+}
+
+/// Mint a small CA plus a client leaf signed by it, so the server can build a
+/// `RootCertStore` that trusts exactly one client: our own bundled frontend.
+fn generate_client_identity(paths: &ClientAuthPaths) -> Result<(), String> {
+    let mut ca_params = CertificateParams::new(Vec::new());
+    ca_params.alg = &rcgen::PKCS_ECDSA_P256_SHA256;
+    ca_params.is_ca = IsCa::Ca(rcgen::BasicConstraints::Unconstrained);
+    let mut ca_name = DistinguishedName::new();
+    ca_name.push(DnType::CommonName, "User Wallet Local CA");
+    ca_params.distinguished_name = ca_name;
+    ca_params.not_before = time::OffsetDateTime::now_utc() - time::Duration::hours(1);
+    ca_params.not_after = time::OffsetDateTime::now_utc() + time::Duration::days(CLIENT_CA_VALIDITY_DAYS);
+    ca_params.key_pair = Some(
+        KeyPair::generate(&rcgen::PKCS_ECDSA_P256_SHA256)
+            .map_err(|e| format!("failed to generate CA keypair: {e}"))?,
+    );
+
+    let ca_cert = Certificate::from_params(ca_params)
+        .map_err(|e| format!("failed to build client CA params: {e}"))?;
+
+    let ca_cert_pem = ca_cert
+        .serialize_pem()
+        .map_err(|e| format!("failed to serialize client CA pem: {e}"))?;
+    let ca_key_pem = ca_cert.serialize_private_key_pem();
+
+    fs::write(&paths.ca_cert_path, ca_cert_pem)
+        .map_err(|e| format!("failed to write client CA certificate: {e}"))?;
+    fs::write(&paths.ca_key_path, ca_key_pem)
+        .map_err(|e| format!("failed to write client CA key: {e}"))?;
+
+    sign_client_leaf(paths, &ca_cert)
+}
+
+/// Mint a fresh, short-lived client leaf signed by the existing CA on disk,
+/// without touching the CA itself. Used both by `generate_client_identity`
+/// (right after minting the CA) and by `rotate_client_certificate` once the
+/// previous leaf is due for renewal, so the server's `RootCertStore` (built
+/// once from the CA at startup) never goes stale out from under a running
+/// listener.
+fn sign_client_leaf(paths: &ClientAuthPaths, ca_cert: &Certificate) -> Result<(), String> {
+    let mut client_params = CertificateParams::new(Vec::new());
+    client_params.alg = &rcgen::PKCS_ECDSA_P256_SHA256;
+    client_params.is_ca = IsCa::ExplicitNoCa;
+    let mut client_name = DistinguishedName::new();
+    client_name.push(DnType::CommonName, CERT_LABEL);
+    client_params.distinguished_name = client_name;
+    let not_before = time::OffsetDateTime::now_utc() - time::Duration::hours(1);
+    let not_after = time::OffsetDateTime::now_utc() + time::Duration::days(CLIENT_CERT_VALIDITY_DAYS);
+    client_params.not_before = not_before;
+    client_params.not_after = not_after;
+    client_params.key_pair = Some(
+        KeyPair::generate(&rcgen::PKCS_ECDSA_P256_SHA256)
+            .map_err(|e| format!("failed to generate client keypair: {e}"))?,
+    );
+
+    let client_cert = Certificate::from_params(client_params)
+        .map_err(|e| format!("failed to build client certificate params: {e}"))?;
+
+    let client_cert_pem = client_cert
+        .serialize_pem_with_signer(ca_cert)
+        .map_err(|e| format!("failed to serialize client certificate pem: {e}"))?;
+    let client_key_pem = client_cert.serialize_private_key_pem();
+
+    fs::write(&paths.client_cert_path, client_cert_pem)
+        .map_err(|e| format!("failed to write client certificate: {e}"))?;
+    fs::write(&paths.client_key_path, client_key_pem)
+        .map_err(|e| format!("failed to write client key: {e}"))?;
+
+    let not_after_str = not_after
+        .format(&time::format_description::well_known::Rfc3339)
+        .map_err(|e| format!("failed to format client certificate expiry: {e}"))?;
+    fs::write(&paths.client_expiry_path, not_after_str)
+        .map_err(|e| format!("failed to persist client certificate expiry: {e}"))?;
+
     Ok(())
 }
 
-fn load_rustls_config(paths: &CertPaths) -> Result<Arc<ServerConfig>, String> {
+/// True if the client leaf is already expired or will expire within the
+/// renewal window. Missing expiry metadata (e.g. an install from before this
+/// check existed) is treated as due for renewal rather than risking a
+/// silently stale cert.
+fn client_cert_needs_renewal(paths: &ClientAuthPaths) -> bool {
+    match fs::read_to_string(&paths.client_expiry_path)
+        .ok()
+        .and_then(|s| time::OffsetDateTime::parse(&s, &time::format_description::well_known::Rfc3339).ok())
+    {
+        Some(not_after) => {
+            let renew_by = not_after - time::Duration::days(CLIENT_CERT_RENEWAL_WINDOW_DAYS);
+            time::OffsetDateTime::now_utc() >= renew_by
+        }
+        None => true,
+    }
+}
+
+/// Re-sign a fresh client leaf from the existing on-disk CA, leaving the CA
+/// itself untouched.
+fn rotate_client_certificate(paths: &ClientAuthPaths) -> Result<(), String> {
+    let ca_cert_pem = fs::read_to_string(&paths.ca_cert_path)
+        .map_err(|e| format!("failed to read client CA certificate: {e}"))?;
+    let ca_key_pem = fs::read_to_string(&paths.ca_key_path)
+        .map_err(|e| format!("failed to read client CA key: {e}"))?;
+    let ca_key_pair = KeyPair::from_pem(&ca_key_pem).map_err(|e| format!("failed to parse client CA key: {e}"))?;
+    let ca_params = CertificateParams::from_ca_cert_pem(&ca_cert_pem, ca_key_pair)
+        .map_err(|e| format!("failed to parse client CA certificate: {e}"))?;
+    let ca_cert = Certificate::from_params(ca_params)
+        .map_err(|e| format!("failed to rebuild client CA for signing: {e}"))?;
+
+    sign_client_leaf(paths, &ca_cert)
+}
+
+/// Parse a peer's DER-encoded client certificate (as handed to us by rustls
+/// after a successful mTLS handshake) and check its Common Name matches
+/// `CERT_LABEL`, i.e. it really is our own bundled frontend's identity.
+pub fn verify_peer_is_wallet_client(der: &[u8]) -> bool {
+    match X509Certificate::from_der(der) {
+        Ok((_, cert)) => cert
+            .subject()
+            .iter_common_name()
+            .any(|cn| cn.as_str().map(|s| s == CERT_LABEL).unwrap_or(false)),
+        Err(_) => false,
+    }
+}
+
+fn load_rustls_config_with_mtls(
+    client_auth_paths: &ClientAuthPaths,
+    cert_resolver: Arc<SwappableCertResolver>,
+) -> Result<Arc<ServerConfig>, String> {
+    let ca_file = fs::File::open(&client_auth_paths.ca_cert_path)
+        .map_err(|e| format!("failed to open client CA certificate: {e}"))?;
+    let mut ca_reader = BufReader::new(ca_file);
+    let ca_certs = rustls_pemfile::certs(&mut ca_reader)
+        .map_err(|e| format!("failed to parse client CA certificate: {e}"))?;
+
+    let mut root_store = RootCertStore::empty();
+    for der in ca_certs {
+        root_store
+            .add(&RustlsCertificate(der))
+            .map_err(|e| format!("failed to add client CA to root store: {e}"))?;
+    }
+
+    let client_cert_verifier = AllowAnyAuthenticatedClient::new(root_store);
+
+    let mut config = ServerConfig::builder()
+        .with_safe_defaults()
+        .with_client_cert_verifier(std::sync::Arc::new(client_cert_verifier))
+        .with_cert_resolver(cert_resolver);
+    config.alpn_protocols = ALPN_PROTOCOLS.iter().map(|p| p.to_vec()).collect();
+
+    Ok(Arc::new(config))
+}
+
+fn read_cert_and_key(paths: &CertPaths) -> Result<(Vec<RustlsCertificate>, PrivateKey), String> {
     let cert_file =
         fs::File::open(&paths.cert_path).map_err(|e| format!("failed to open certificate: {e}"))?;
     let mut cert_reader = BufReader::new(cert_file);
@@ -126,38 +750,132 @@ fn load_rustls_config(paths: &CertPaths) -> Result<Arc<ServerConfig>, String> {
         .into_iter()
         .next()
         .ok_or_else(|| "no private key found".to_string())?;
-    let key = PrivateKey(key_bytes);
 
-    let config = ServerConfig::builder()
+    Ok((cert_chain, PrivateKey(key_bytes)))
+}
+
+fn load_rustls_config(cert_resolver: Arc<SwappableCertResolver>) -> Result<Arc<ServerConfig>, String> {
+    let mut config = ServerConfig::builder()
         .with_safe_defaults()
         .with_no_client_auth()
-        .with_single_cert(cert_chain, key)
-        .map_err(|e| format!("failed to build rustls config: {e}"))?;
+        .with_cert_resolver(cert_resolver);
+    config.alpn_protocols = ALPN_PROTOCOLS.iter().map(|p| p.to_vec()).collect();
 
     Ok(Arc::new(config))
 }
 
-fn trust_certificate(paths: &CertPaths, newly_created: bool) -> Result<(), String> {
+/// Outcome of attempting to install the localhost cert into one trust store.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrustOutcome {
+    Trusted,
+    AlreadyTrusted,
+    Skipped,
+    Failed { detail: String },
+}
+
+/// Per-store results of a `trust_certificate` pass, so callers can tell
+/// *which* of macOS Keychain / Windows Root / p11-kit / Chrome NSS actually
+/// picked up the cert instead of just "it didn't throw".
+#[derive(Debug, Clone, Default)]
+pub struct TrustReport {
+    pub entries: Vec<(String, TrustOutcome)>,
+}
+
+impl TrustReport {
+    fn push(&mut self, store: &str, outcome: TrustOutcome) {
+        self.entries.push((store.to_string(), outcome));
+    }
+
+    pub fn all_failed(&self) -> bool {
+        !self.entries.is_empty()
+            && self
+                .entries
+                .iter()
+                .all(|(_, outcome)| matches!(outcome, TrustOutcome::Failed { .. }))
+    }
+}
+
+/// Remove any previously-installed copy of our localhost cert from the OS
+/// trust stores before re-adding the freshly rotated one, so a compromised
+/// or simply stale leaf never lingers as trusted. NSS stores (Chrome and
+/// Firefox) already do delete-then-add by label on every install, so only
+/// macOS Keychain and the Windows Root store need an explicit pass here.
+fn revoke_stale_trust(_paths: &CertPaths, report: &mut TrustReport) {
     #[cfg(target_os = "macos")]
     {
-        trust_on_macos(&paths.cert_path, newly_created)?;
+        for label in std::iter::once(CERT_LABEL).chain(CERT_LABEL_ALIASES.iter().copied()) {
+            let status = Command::new("security")
+                .arg("delete-certificate")
+                .arg("-c")
+                .arg(label)
+                .status();
+            match status {
+                Ok(status) if status.success() => {
+                    report.push("macos-keychain-revoke", TrustOutcome::Trusted)
+                }
+                Ok(_) => {
+                    // Nothing under this label/alias; not an error.
+                }
+                Err(e) => report.push(
+                    "macos-keychain-revoke",
+                    TrustOutcome::Failed {
+                        detail: format!("failed to execute security tool: {e}"),
+                    },
+                ),
+            }
+        }
     }
 
     #[cfg(target_os = "windows")]
     {
-        trust_on_windows(&paths.cert_der_path, newly_created)?;
+        let status = Command::new("certutil")
+            .arg("-delstore")
+            .arg("Root")
+            .arg(CERT_LABEL)
+            .status();
+        match status {
+            Ok(status) if status.success() => {
+                report.push("windows-root-revoke", TrustOutcome::Trusted)
+            }
+            Ok(_) => {}
+            Err(e) => report.push(
+                "windows-root-revoke",
+                TrustOutcome::Failed {
+                    detail: format!("failed to execute certutil: {e}"),
+                },
+            ),
+        }
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        let _ = report;
+    }
+}
+
+fn trust_certificate(paths: &CertPaths, newly_created: bool) -> Result<TrustReport, String> {
+    let mut report = TrustReport::default();
+
+    #[cfg(target_os = "macos")]
+    {
+        trust_on_macos(&paths.cert_path, newly_created, &mut report);
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        trust_on_windows(&paths.cert_der_path, newly_created, &mut report);
     }
 
     #[cfg(target_os = "linux")]
     {
-        trust_on_linux(&paths.cert_path, newly_created)?;
+        trust_on_linux(&paths.cert_path, newly_created, &mut report);
     }
 
-    Ok(())
+    Ok(report)
 }
 
 #[cfg(target_os = "macos")]
-fn trust_on_macos(cert_path: &Path, newly_created: bool) -> Result<(), String> {
+fn trust_on_macos(cert_path: &Path, newly_created: bool, report: &mut TrustReport) {
     if !newly_created {
         // If any known label is already trusted, skip re-adding (avoids repeated prompts)
         for label in std::iter::once(CERT_LABEL).chain(CERT_LABEL_ALIASES.iter().copied()) {
@@ -168,19 +886,28 @@ fn trust_on_macos(cert_path: &Path, newly_created: bool) -> Result<(), String> {
                 .status()
             {
                 if status.success() {
-                    return Ok(());
+                    report.push("macos-keychain", TrustOutcome::AlreadyTrusted);
+                    return;
                 }
             }
         }
     }
 
-    let keychain = env::var("HOME")
-        .map(PathBuf::from)
-        .map(|mut path| {
+    let keychain = match env::var("HOME").map(PathBuf::from) {
+        Ok(mut path) => {
             path.push("Library/Keychains/login.keychain-db");
             path
-        })
-        .map_err(|e| format!("failed to resolve keychain path: {e}"))?;
+        }
+        Err(e) => {
+            report.push(
+                "macos-keychain",
+                TrustOutcome::Failed {
+                    detail: format!("failed to resolve keychain path: {e}"),
+                },
+            );
+            return;
+        }
+    };
 
     let status = Command::new("security")
         .arg("add-trusted-cert")
@@ -193,25 +920,29 @@ fn trust_on_macos(cert_path: &Path, newly_created: bool) -> Result<(), String> {
         .status();
 
     match status {
-        Ok(status) if status.success() => Ok(()),
-        Ok(status) => {
-            eprintln!(
-                "failed to add certificate to macOS keychain (code {}), continuing",
-                status
-            );
-            Ok(())
-        }
-        Err(e) => {
-            eprintln!("failed to execute security tool: {e}");
-            Ok(())
-        }
+        Ok(status) if status.success() => report.push("macos-keychain", TrustOutcome::Trusted),
+        Ok(status) => report.push(
+            "macos-keychain",
+            TrustOutcome::Failed {
+                detail: format!("add-trusted-cert exited with {status}"),
+            },
+        ),
+        Err(e) => report.push(
+            "macos-keychain",
+            TrustOutcome::Failed {
+                detail: format!("failed to execute security tool: {e}"),
+            },
+        ),
     }
+
+    trust_firefox_nss(cert_path, report);
 }
 
 #[cfg(target_os = "windows")]
-fn trust_on_windows(cert_der: &Path, newly_created: bool) -> Result<(), String> {
+fn trust_on_windows(cert_der: &Path, newly_created: bool, report: &mut TrustReport) {
     if !newly_created {
-        return Ok(());
+        report.push("windows-root", TrustOutcome::AlreadyTrusted);
+        return;
     }
 
     let status = Command::new("certutil")
@@ -222,35 +953,60 @@ fn trust_on_windows(cert_der: &Path, newly_created: bool) -> Result<(), String>
         .status();
 
     match status {
-        Ok(status) if status.success() => Ok(()),
-        Ok(status) => {
-            eprintln!(
-                "failed to add certificate to Windows store (code {}), continuing",
-                status
-            );
-            Ok(())
-        }
-        Err(e) => {
-            eprintln!("failed to execute certutil: {e}");
-            Ok(())
-        }
+        Ok(status) if status.success() => report.push("windows-root", TrustOutcome::Trusted),
+        Ok(status) => report.push(
+            "windows-root",
+            TrustOutcome::Failed {
+                detail: format!("certutil -addstore exited with {status}"),
+            },
+        ),
+        Err(e) => report.push(
+            "windows-root",
+            TrustOutcome::Failed {
+                detail: format!("failed to execute certutil: {e}"),
+            },
+        ),
     }
 }
 
 #[cfg(target_os = "linux")]
-fn trust_on_linux(cert_path: &Path, newly_created: bool) -> Result<(), String> {
-    let home = env::var("HOME").map_err(|e| format!("failed to resolve HOME: {e}"))?;
+fn trust_on_linux(cert_path: &Path, newly_created: bool, report: &mut TrustReport) {
+    let home = match env::var("HOME") {
+        Ok(home) => home,
+        Err(e) => {
+            report.push(
+                "linux-ca-certificates",
+                TrustOutcome::Failed {
+                    detail: format!("failed to resolve HOME: {e}"),
+                },
+            );
+            return;
+        }
+    };
     let mut local_store = PathBuf::from(&home);
     local_store.push(".local/share/ca-certificates");
 
     if let Err(e) = fs::create_dir_all(&local_store) {
-        eprintln!("failed to prepare local CA directory: {e}");
+        report.push(
+            "linux-ca-certificates",
+            TrustOutcome::Failed {
+                detail: format!("failed to prepare local CA directory: {e}"),
+            },
+        );
     } else {
         let target = local_store.join("metanet-localhost.crt");
         if newly_created || !target.exists() {
-            if let Err(e) = fs::copy(cert_path, &target) {
-                eprintln!("failed to copy certificate into local CA store: {e}");
+            match fs::copy(cert_path, &target) {
+                Ok(_) => report.push("linux-ca-certificates", TrustOutcome::Trusted),
+                Err(e) => report.push(
+                    "linux-ca-certificates",
+                    TrustOutcome::Failed {
+                        detail: format!("failed to copy certificate into local CA store: {e}"),
+                    },
+                ),
             }
+        } else {
+            report.push("linux-ca-certificates", TrustOutcome::AlreadyTrusted);
         }
     }
 
@@ -262,32 +1018,124 @@ fn trust_on_linux(cert_path: &Path, newly_created: bool) -> Result<(), String> {
             .arg(store_arg)
             .arg(cert_path)
             .status();
-        if let Err(e) = status {
-            eprintln!("failed to execute trust tool: {e}");
+        match status {
+            Ok(status) if status.success() => report.push("linux-p11-kit", TrustOutcome::Trusted),
+            Ok(status) => report.push(
+                "linux-p11-kit",
+                TrustOutcome::Failed {
+                    detail: format!("trust anchor exited with {status}"),
+                },
+            ),
+            Err(e) => report.push(
+                "linux-p11-kit",
+                TrustOutcome::Failed {
+                    detail: format!("failed to execute trust tool: {e}"),
+                },
+            ),
         }
+    } else {
+        report.push("linux-p11-kit", TrustOutcome::Skipped);
     }
 
-    trust_chrome_on_linux(cert_path)?;
-    Ok(())
+    trust_chrome_on_linux(cert_path, report);
+    trust_firefox_nss(cert_path, report);
 }
 
 #[cfg(target_os = "linux")]
-fn trust_chrome_on_linux(cert_path: &Path) -> Result<(), String> {
-    let home = env::var("HOME").map_err(|e| format!("failed to resolve HOME: {e}"))?;
+fn trust_chrome_on_linux(cert_path: &Path, report: &mut TrustReport) {
+    let home = match env::var("HOME") {
+        Ok(home) => home,
+        Err(e) => {
+            report.push(
+                "linux-chrome-nss",
+                TrustOutcome::Failed {
+                    detail: format!("failed to resolve HOME: {e}"),
+                },
+            );
+            return;
+        }
+    };
     let mut nss_dir = PathBuf::from(&home);
     nss_dir.push(".pki/nssdb");
 
     if !nss_dir.exists() {
         // Nothing to do if NSS database is missing.
-        return Ok(());
+        report.push("linux-chrome-nss", TrustOutcome::Skipped);
+        return;
+    }
+
+    install_cert_into_nss_db(&nss_dir, cert_path, "linux-chrome-nss", report);
+}
+
+/// Candidate parent directories that contain one subdirectory per Firefox
+/// profile, each with its own `cert9.db`. Covers native, snap and flatpak
+/// installs on Linux and the standard profile location on macOS.
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn firefox_profile_roots(home: &str) -> Vec<PathBuf> {
+    vec![
+        PathBuf::from(home).join(".mozilla/firefox"),
+        PathBuf::from(home).join("snap/firefox/common/.mozilla/firefox"),
+        PathBuf::from(home).join(".var/app/org.mozilla.firefox/.mozilla/firefox"),
+        PathBuf::from(home).join("Library/Application Support/Firefox/Profiles"),
+    ]
+}
+
+/// Install the localhost cert, labeled `CERT_LABEL`, into every Firefox NSS
+/// profile found under `firefox_profile_roots`, de-duplicating by label the
+/// same way the Chrome nssdb install does. Certificates under the legacy
+/// alias labels are left in place.
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn trust_firefox_nss(cert_path: &Path, report: &mut TrustReport) {
+    let home = match env::var("HOME") {
+        Ok(home) => home,
+        Err(e) => {
+            report.push(
+                "firefox-nss",
+                TrustOutcome::Failed {
+                    detail: format!("failed to resolve HOME: {e}"),
+                },
+            );
+            return;
+        }
+    };
+
+    let mut found_any = false;
+    for root in firefox_profile_roots(&home) {
+        let Ok(entries) = fs::read_dir(&root) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let profile_dir = entry.path();
+            if !profile_dir.is_dir() || !profile_dir.join("cert9.db").exists() {
+                continue;
+            }
+            found_any = true;
+            let store = format!("firefox-nss:{}", profile_dir.display());
+            install_cert_into_nss_db(&profile_dir, cert_path, &store, report);
+        }
     }
 
+    if !found_any {
+        report.push("firefox-nss", TrustOutcome::Skipped);
+    }
+}
+
+/// Install `cert_path` (labeled `CERT_LABEL`) into the `sql:`-format NSS
+/// database at `db_dir`, replacing any certificate already installed under
+/// that label.
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn install_cert_into_nss_db(db_dir: &Path, cert_path: &Path, store: &str, report: &mut TrustReport) {
     if !command_exists("certutil") {
-        eprintln!("certutil not found; cannot add certificate to Chrome NSS store");
-        return Ok(());
+        report.push(
+            store,
+            TrustOutcome::Failed {
+                detail: "certutil not found; cannot install certificate into NSS store".into(),
+            },
+        );
+        return;
     }
 
-    let db_path = format!("sql:{}", nss_dir.to_string_lossy());
+    let db_path = format!("sql:{}", db_dir.to_string_lossy());
     let label = CERT_LABEL;
 
     // Remove any existing certificate with the same label.
@@ -312,22 +1160,23 @@ fn trust_chrome_on_linux(cert_path: &Path) -> Result<(), String> {
         .status();
 
     match status {
-        Ok(status) if status.success() => Ok(()),
-        Ok(status) => {
-            eprintln!(
-                "failed to install certificate into Chrome NSS store (code {})",
-                status
-            );
-            Ok(())
-        }
-        Err(e) => {
-            eprintln!("failed to execute certutil: {e}");
-            Ok(())
-        }
+        Ok(status) if status.success() => report.push(store, TrustOutcome::Trusted),
+        Ok(status) => report.push(
+            store,
+            TrustOutcome::Failed {
+                detail: format!("failed to install certificate into NSS store (code {status})"),
+            },
+        ),
+        Err(e) => report.push(
+            store,
+            TrustOutcome::Failed {
+                detail: format!("failed to execute certutil: {e}"),
+            },
+        ),
     }
 }
 
-#[cfg(target_os = "linux")]
+#[cfg(any(target_os = "linux", target_os = "macos"))]
 fn command_exists(command: &str) -> bool {
     Command::new("sh")
         .arg("-c")